@@ -0,0 +1,7 @@
+#!/bin/sh
+# builds ofx without the optional features, then with each one on its own
+set -e
+cargo build -p ofx --no-default-features
+for feature in natron vegas opengl dialog parametric camera log4rs tracing instrumentation; do
+	cargo build -p ofx --no-default-features --features $feature
+done
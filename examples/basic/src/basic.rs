@@ -1,5 +1,5 @@
 use ofx::*;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 plugin_module!(
 	"net.itadinanta.ofx-rs.basic",
@@ -10,7 +10,7 @@ plugin_module!(
 
 #[derive(Default)]
 struct SimplePlugin {
-	host_supports_multiple_clip_depths: Bool,
+	host_supports_multiple_clip_depths: AtomicBool,
 }
 
 impl SimplePlugin {
@@ -36,148 +36,34 @@ struct MyInstanceData {
 	scale_a_param: ParamHandle<Double>,
 }
 
-struct TileProcessor<'a, T, M>
-where
-	T: PixelFormat,
-	M: PixelFormatAlpha,
-{
-	instance: ImageEffectHandle,
-	scale: RGBAColourD,
-	src: ImageDescriptor<'a, T>,
-	dst: ImageTileMut<'a, T>,
-	mask: Option<ImageDescriptor<'a, M>>,
-	render_window: RectI,
-}
-
-// Members of the TileProcessor are either:
-// - shared + read only
-// - shareable handles and memory blocks from OFX (for which we can spray and pray)
-// - owned by the tileprocessor
-// so we can assume it can be processed across multiple threads even if rustc says no
-//
-unsafe impl<'a, T, M> Send for TileProcessor<'a, T, M>
-where
-	T: PixelFormat + ScaleMix,
-	M: PixelFormatAlpha,
-{
-}
-
-impl<'a, T, M> TileProcessor<'a, T, M>
+fn scale_row<T, M>(scale: &RGBAColourD, src_row: &[T], src_mask: Option<&[M]>, dst_row: &mut [T])
 where
 	T: PixelFormat + ScaleMix,
 	M: PixelFormatAlpha,
 {
-	fn new(
-		instance: ImageEffectHandle,
-		r_scale: Double,
-		g_scale: Double,
-		b_scale: Double,
-		a_scale: Double,
-		src: ImageDescriptor<'a, T>,
-		dst: ImageTileMut<'a, T>,
-		mask: Option<ImageDescriptor<'a, M>>,
-		render_window: RectI,
-	) -> Self {
-		let scale = RGBAColourD {
-			r: r_scale,
-			g: g_scale,
-			b: b_scale,
-			a: a_scale,
-		};
-		TileProcessor {
-			instance,
-			scale,
-			src,
-			dst,
-			mask,
-			render_window,
-		}
-	}
-}
-
-struct TileDispatch<'a, T, M>
-where
-	T: PixelFormat + ScaleMix,
-	M: PixelFormatAlpha,
-{
-	tiles: Arc<Mutex<Vec<TileProcessor<'a, T, M>>>>,
-}
-
-impl<'a, T, M> TileDispatch<'a, T, M>
-where
-	T: PixelFormat + ScaleMix,
-	M: PixelFormatAlpha,
-{
-	fn new(tiles: Vec<TileProcessor<'a, T, M>>) -> Self {
-		TileDispatch {
-			tiles: Arc::new(Mutex::new(tiles)),
-		}
-	}
-
-	fn pop(&mut self) -> Option<TileProcessor<'a, T, M>> {
-		if let Ok(ref mut tiles) = self.tiles.lock() {
-			tiles.pop()
-		} else {
-			None
+	match src_mask {
+		None => {
+			for (dst, src) in dst_row.iter_mut().zip(src_row.iter()) {
+				*dst = src.scaled(scale);
+			}
 		}
-	}
-}
-
-impl<'a, T, M> Runnable for TileDispatch<'a, T, M>
-where
-	T: PixelFormat + ScaleMix,
-	M: PixelFormatAlpha,
-{
-	fn run(&mut self, _thread_index: UnsignedInt, _thread_max: UnsignedInt) {
-		while let Some(mut tile) = self.pop() {
-			if tile.do_processing().is_err() {
-				break;
-			};
+		Some(src_mask) => {
+			for ((dst, src), mask) in dst_row.iter_mut().zip(src_row.iter()).zip(src_mask) {
+				let mask0 = mask.to_f32();
+				*dst = src.mix(&src.scaled(scale), mask0);
+			}
 		}
 	}
 }
 
-trait ProcessRGBA<'a, T, M> {
-	fn do_processing(&'a mut self) -> Result<()>;
-}
-
-impl<'a, T, M> ProcessRGBA<'a, T, M> for TileProcessor<'a, T, M>
+fn clear_row<T>(dst_row: &mut [T])
 where
-	T: PixelFormat + ScaleMix,
-	M: PixelFormatAlpha,
+	T: PixelFormat,
 {
-	fn do_processing(&'a mut self) -> Result<()> {
-		let scale = self.scale;
-		let proc_window = self.render_window;
-		for y in self.dst.y1.max(proc_window.y1)..self.dst.y2.min(proc_window.y2) {
-			let dst_row = self.dst.row_range(proc_window.x1, proc_window.x2, y);
-			let src_row = self.src.row_range(proc_window.x1, proc_window.x2, y);
-
-			if self.instance.abort()? {
-				break;
-			}
-
-			let src_mask = self
-				.mask
-				.as_ref()
-				.map(|mask| mask.row_range(proc_window.x1, proc_window.x2, y));
-
-			match src_mask {
-				None => {
-					for (dst, src) in dst_row.iter_mut().zip(src_row.iter()) {
-						*dst = src.scaled(&scale);
-					}
-				}
-				Some(src_mask) => {
-					for ((dst, src), mask) in dst_row.iter_mut().zip(src_row.iter()).zip(src_mask) {
-						let mask0 = mask.to_f32();
-						*dst = src.mix(&src.scaled(&scale), mask0);
-					}
-				}
-			}
+	for dst in dst_row.iter_mut() {
+		for c in 0..T::num_components() {
+			*dst.channel_mut(c) = T::ChannelValue::from_f32(0.0);
 		}
-
-		Ok(())
 	}
 }
 
@@ -192,17 +78,16 @@ const PARAM_COMPONENT_SCALES_NAME: &str = "componentScales";
 
 impl Execute for SimplePlugin {
 	#[allow(clippy::float_cmp)]
-	fn execute(&mut self, plugin_context: &PluginContext, action: &mut Action) -> Result<Int> {
+	fn execute(&self, plugin_context: &PluginContext, action: &mut Action) -> Result<Int> {
 		use Action::*;
 		match *action {
 			Render(ref mut effect, ref in_args) => {
 				let time = in_args.get_time()?;
-				// TODO: what happens if render_window < full size?
 				let render_window = in_args.get_render_window()?;
-				let instance_data: &mut MyInstanceData = effect.get_instance_data()?;
+				let instance_data: &MyInstanceData = effect.get_instance_data()?;
 
 				let source_image = instance_data.source_clip.get_image(time)?;
-				let output_image = instance_data.output_clip.get_image_mut(time)?;
+				let output_image = instance_data.output_clip.clone().get_image_mut(time)?;
 				let mask_image = match instance_data.mask_clip {
 					None => None,
 					Some(ref mask_clip) => {
@@ -215,52 +100,61 @@ impl Execute for SimplePlugin {
 				};
 
 				let (sv, sr, sg, sb, sa) = instance_data.get_scale_components(time)?;
-				let (r_scale, g_scale, b_scale, a_scale) = (sv * sr, sv * sg, sv * sb, sv * sa);
-				let mut output_image = output_image.borrow_mut();
-				let num_threads = plugin_context.num_threads()?;
-				let num_tiles = num_threads as usize;
-				macro_rules! tiles {
-					($rgba_format:ty, $mask_format:ty) => {{
-						output_image
-							.get_tiles_mut::<$rgba_format>(num_tiles)?
-							.into_iter()
-							.map(|tile| {
-								let src = source_image.get_descriptor::<$rgba_format>().unwrap();
-								let mask = mask_image
-									.as_ref()
-									.and_then(|mask| mask.get_descriptor::<$mask_format>().ok());
-								TileProcessor::new(
-									effect.clone(),
-									r_scale,
-									g_scale,
-									b_scale,
-									a_scale,
-									src,
-									tile,
-									mask,
-									render_window,
-								)
-							})
-						}};
-				}
-
-				macro_rules! process_tiles {
+				let scale = RGBAColourD {
+					r: sv * sr,
+					g: sv * sg,
+					b: sv * sb,
+					a: sv * sa,
+				};
+				let mut output_image = output_image.lock()?;
+				macro_rules! process_rows {
 					($rgba_format:ty, $mask_format:ty) => {{
-						let mut queue =
-							TileDispatch::new(tiles!($rgba_format, $mask_format).collect());
-						plugin_context.run_in_threads(num_threads, &mut queue)?;
+						let src = source_image.get_descriptor::<$rgba_format>()?;
+						let mask = match mask_image {
+							Some(ref mask) => Some(mask.get_descriptor::<$mask_format>()?),
+							None => None,
+						};
+						let dst = output_image.get_descriptor_mut::<$rgba_format>()?;
+						let output_window = render_window.intersection(&dst.bounds());
+						// the source and the mask might not cover the whole render window,
+						// what they miss is transparent black
+						let mut window = output_window.intersection(&src.bounds());
+						if let Some(ref mask) = mask {
+							window = window.intersection(&mask.bounds());
+						}
+						plugin_context.par_rows(effect, dst, output_window, |y, dst_row| {
+							if window.is_empty() || y < window.y1 || y >= window.y2 {
+								clear_row(dst_row);
+								return Ok(());
+							}
+							let (left, dst_row) =
+								dst_row.split_at_mut((window.x1 - output_window.x1) as usize);
+							let (dst_row, right) = dst_row.split_at_mut(window.width() as usize);
+							clear_row(left);
+							clear_row(right);
+							let src_row = src.row_range(window.x1, window.x2, y);
+							let src_mask = mask
+								.as_ref()
+								.map(|mask| mask.row_range(window.x1, window.x2, y));
+							scale_row(&scale, src_row, src_mask, dst_row);
+							Ok(())
+						})?;
 						}};
 				}
 				match (
 					output_image.get_pixel_depth()?,
-					output_image.get_components()?,
+					output_image.get_components()?.standard(),
 				) {
-					(BitDepth::Float, ImageComponent::RGBA) => process_tiles!(RGBAColourF, f32),
-					(BitDepth::Byte, ImageComponent::RGBA) => process_tiles!(RGBAColourB, u8),
-					(BitDepth::Short, ImageComponent::RGBA) => process_tiles!(RGBAColourS, u16),
-					(BitDepth::Float, ImageComponent::Alpha) => process_tiles!(f32, f32),
-					(BitDepth::Byte, ImageComponent::Alpha) => process_tiles!(u8, u8),
-					(BitDepth::Short, ImageComponent::Alpha) => process_tiles!(u16, u16),
+					(BitDepth::Float, Some(ImageComponent::RGBA)) => {
+						process_rows!(RGBAColourF, f32)
+					}
+					(BitDepth::Byte, Some(ImageComponent::RGBA)) => process_rows!(RGBAColourB, u8),
+					(BitDepth::Short, Some(ImageComponent::RGBA)) => {
+						process_rows!(RGBAColourS, u16)
+					}
+					(BitDepth::Float, Some(ImageComponent::Alpha)) => process_rows!(f32, f32),
+					(BitDepth::Byte, Some(ImageComponent::Alpha)) => process_rows!(u8, u8),
+					(BitDepth::Short, Some(ImageComponent::Alpha)) => process_rows!(u16, u16),
 					(_, _) => return FAILED,
 				}
 
@@ -319,15 +213,14 @@ impl Execute for SimplePlugin {
 
 			GetRegionsOfInterest(ref mut effect, ref in_args, ref mut out_args) => {
 				let roi = in_args.get_region_of_interest()?;
+				let instance_data: &MyInstanceData = effect.get_instance_data()?;
 
-				out_args.set_raw(image_clip_prop_roi!(clip_source!()), &roi)?;
+				out_args.set_roi(&instance_data.source_clip, roi)?;
 
-				if effect
-					.get_instance_data::<MyInstanceData>()?
-					.is_general_effect
-					&& effect.get_clip(clip_mask!())?.get_connected()?
-				{
-					out_args.set_raw(image_clip_prop_roi!(clip_mask!()), &roi)?;
+				if let Some(ref mask_clip) = instance_data.mask_clip {
+					if instance_data.is_general_effect && mask_clip.get_connected()? {
+						out_args.set_roi(mask_clip, roi)?;
+					}
 				}
 
 				OK
@@ -344,18 +237,19 @@ impl Execute for SimplePlugin {
 				let my_data: &MyInstanceData = effect.get_instance_data()?;
 				let bit_depth = my_data.source_clip.get_pixel_depth()?;
 				let image_component = my_data.source_clip.get_components()?;
-				let output_component = match image_component {
-					ImageComponent::RGBA | ImageComponent::RGB => ImageComponent::RGBA,
-					_ => ImageComponent::Alpha,
+				let output_component = if image_component.is_rgb() {
+					ImageComponent::RGBA
+				} else {
+					ImageComponent::Alpha
 				};
-				out_args.set_raw(
-					image_clip_prop_components!(clip_output!()),
-					output_component.to_bytes(),
-				)?;
-
-				if self.host_supports_multiple_clip_depths {
-					out_args
-						.set_raw(image_clip_prop_depth!(clip_output!()), bit_depth.to_bytes())?;
+				let supports_multiple_clip_depths = self
+					.host_supports_multiple_clip_depths
+					.load(Ordering::Relaxed);
+
+				let mut output = out_args.clip(clip_output!())?;
+				output.set_components(output_component)?;
+				if supports_multiple_clip_depths {
+					output.set_depth(bit_depth)?;
 				}
 
 				if my_data.is_general_effect {
@@ -366,15 +260,10 @@ impl Execute for SimplePlugin {
 						.unwrap_or_default();
 
 					if is_mask_connected {
-						out_args.set_raw(
-							image_clip_prop_components!(clip_mask!()),
-							ImageComponent::Alpha.to_bytes(),
-						)?;
-						if self.host_supports_multiple_clip_depths {
-							out_args.set_raw(
-								image_clip_prop_depth!(clip_mask!()),
-								bit_depth.to_bytes(),
-							)?;
+						let mut mask = out_args.clip(clip_mask!())?;
+						mask.set_components(ImageComponent::Alpha)?;
+						if supports_multiple_clip_depths {
+							mask.set_depth(bit_depth)?;
 						}
 					}
 				}
@@ -439,7 +328,7 @@ impl Execute for SimplePlugin {
 				}
 
 				fn define_scale_param(
-					param_set: &mut ParamSetHandle,
+					param_set: &mut ParamSetDescriptorHandle,
 					name: &str,
 					label: &'static str,
 					script_name: &'static str,
@@ -533,9 +422,12 @@ impl Execute for SimplePlugin {
 			}
 
 			Describe(ref mut effect) => {
-				self.host_supports_multiple_clip_depths = plugin_context
-					.get_host()
-					.get_supports_multiple_clip_depths()?;
+				self.host_supports_multiple_clip_depths.store(
+					plugin_context
+						.get_host()
+						.get_supports_multiple_clip_depths()?,
+					Ordering::Relaxed,
+				);
 
 				let mut effect_properties: EffectDescriptor = effect.properties()?;
 				effect_properties.set_grouping("Ofx-rs")?;
@@ -564,25 +456,28 @@ impl Execute for SimplePlugin {
 
 impl SimplePlugin {
 	fn set_per_component_scale_enabledness(effect: &mut ImageEffectHandle) -> Result<()> {
-		let instance_data: &mut MyInstanceData = effect.get_instance_data()?;
+		let instance_data: &MyInstanceData = effect.get_instance_data()?;
 		let input_clip = effect.get_simple_input_clip()?;
 		let is_input_rgb = input_clip.get_connected()? && input_clip.get_components()?.is_rgb();
+		// the handles are shared, setting properties goes through a copy of each
 		instance_data
 			.per_component_scale_param
+			.clone()
 			.set_enabled(is_input_rgb)?;
 		let per_component_scale =
 			is_input_rgb && instance_data.per_component_scale_param.get_value()?;
-		for scale_param in &mut [
-			&mut instance_data.scale_r_param,
-			&mut instance_data.scale_g_param,
-			&mut instance_data.scale_b_param,
-			&mut instance_data.scale_a_param,
+		for scale_param in &[
+			&instance_data.scale_r_param,
+			&instance_data.scale_g_param,
+			&instance_data.scale_b_param,
+			&instance_data.scale_a_param,
 		] {
-			scale_param.set_enabled(per_component_scale)?;
-			instance_data
-				.scale_param
-				.set_enabled(!per_component_scale)?
+			ParamHandle::clone(scale_param).set_enabled(per_component_scale)?;
 		}
+		instance_data
+			.scale_param
+			.clone()
+			.set_enabled(!per_component_scale)?;
 
 		Ok(())
 	}
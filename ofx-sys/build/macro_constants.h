@@ -13,4 +13,6 @@ enum eOfxStatus {
 	ErrMissingHostFeature = kOfxStatErrMissingHostFeature,
 };
 
+#ifdef OFX_SYS_FEATURE_OPENGL
 #define kOfxImageEffectOpenGLRenderSuite "OfxImageEffectOpenGLRenderSuite"
+#endif
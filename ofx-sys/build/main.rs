@@ -1,14 +1,40 @@
+#[cfg(feature = "bindgen")]
 extern crate bindgen;
 
+#[cfg(feature = "bindgen")]
 use std::env;
+#[cfg(feature = "bindgen")]
 use std::path::PathBuf;
 
+#[cfg(feature = "bindgen")]
+const EXTENSION_FEATURES: &[&str] = &[
+	"natron",
+	"vegas",
+	"opengl",
+	"dialog",
+	"parametric",
+	"camera",
+];
+
+// without the bindgen feature the checked in src/bindings.rs is used, and
+// neither libclang nor the openfx submodule are needed
+#[cfg(not(feature = "bindgen"))]
+fn main() {}
+
+#[cfg(feature = "bindgen")]
 fn main() {
 	// Tell cargo to tell rustc to link a library.
 	// println!("cargo:rustc-link-lib=openfx");
 	let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
 	//println!("Bindings generated at {:?}", bindings_path);
-	let bindings = bindgen::Builder::default()
+	// extension headers are only included when their feature is enabled
+	let mut builder = bindgen::Builder::default();
+	for feature in EXTENSION_FEATURES {
+		if env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some() {
+			builder = builder.clang_arg(format!("-DOFX_SYS_FEATURE_{}", feature.to_uppercase()));
+		}
+	}
+	let bindings = builder
 		.clang_arg("-I./native/openfx/include")
 		.clang_arg("-I./../../native/openfx/include")
 		.rust_target(bindgen::RustTarget::Nightly)
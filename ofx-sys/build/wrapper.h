@@ -11,15 +11,29 @@
 #include "ofxParam.h"
 #include "ofxMessage.h"
 #include "ofxProperty.h"
+#ifdef OFX_SYS_FEATURE_DIALOG
 #include "ofxDialog.h"
+#endif
 #include "ofxInteract.h"
 #include "ofxProgress.h"
 #include "ofxKeySyms.h"
 #include "ofxMultiThread.h"
+#ifdef OFX_SYS_FEATURE_NATRON
 #include "ofxNatron.h"
+#endif
+#ifdef OFX_SYS_FEATURE_OPENGL
 #include "ofxOpenGLRender.h"
+#endif
+#ifdef OFX_SYS_FEATURE_PARAMETRIC
 #include "ofxParametricParam.h"
+#endif
+#ifdef OFX_SYS_FEATURE_VEGAS
 #include "ofxSonyVegas.h"
+#endif
 #include "ofxTimeLine.h"
+#include "nuke/fnOfxExtensions.h"
+#ifdef OFX_SYS_FEATURE_CAMERA
+#include "nuke/camera.h"
+#endif
 
 #include "macro_constants.h"
@@ -0,0 +1,1564 @@
+// Pre-generated bindings for OpenFX 1.4, build with `--features bindgen` to
+// regenerate them from native/openfx/include into OUT_DIR instead.
+// Generated by rust-bindgen with every extension header included, then split
+// by hand: the declarations of each optional header were moved, unchanged and
+// in their original order, to the bindings_<feature>.rs file of its feature.
+
+pub const kOfxActionLoad: &'static [u8; 14usize] = b"OfxActionLoad\0";
+pub const kOfxActionDescribe: &'static [u8; 18usize] = b"OfxActionDescribe\0";
+pub const kOfxActionUnload: &'static [u8; 16usize] = b"OfxActionUnload\0";
+pub const kOfxActionPurgeCaches: &'static [u8; 21usize] = b"OfxActionPurgeCaches\0";
+pub const kOfxActionSyncPrivateData: &'static [u8; 25usize] = b"OfxActionSyncPrivateData\0";
+pub const kOfxActionCreateInstance: &'static [u8; 24usize] = b"OfxActionCreateInstance\0";
+pub const kOfxActionDestroyInstance: &'static [u8; 25usize] = b"OfxActionDestroyInstance\0";
+pub const kOfxActionInstanceChanged: &'static [u8; 25usize] = b"OfxActionInstanceChanged\0";
+pub const kOfxActionBeginInstanceChanged: &'static [u8; 30usize] =
+    b"OfxActionBeginInstanceChanged\0";
+pub const kOfxActionEndInstanceChanged: &'static [u8; 28usize] = b"OfxActionEndInstanceChanged\0";
+pub const kOfxActionBeginInstanceEdit: &'static [u8; 27usize] = b"OfxActionBeginInstanceEdit\0";
+pub const kOfxActionEndInstanceEdit: &'static [u8; 25usize] = b"OfxActionEndInstanceEdit\0";
+pub const kOfxPropAPIVersion: &'static [u8; 18usize] = b"OfxPropAPIVersion\0";
+pub const kOfxPropTime: &'static [u8; 12usize] = b"OfxPropTime\0";
+pub const kOfxPropIsInteractive: &'static [u8; 21usize] = b"OfxPropIsInteractive\0";
+pub const kOfxPluginPropFilePath: &'static [u8; 22usize] = b"OfxPluginPropFilePath\0";
+pub const kOfxPropInstanceData: &'static [u8; 20usize] = b"OfxPropInstanceData\0";
+pub const kOfxPropType: &'static [u8; 12usize] = b"OfxPropType\0";
+pub const kOfxPropName: &'static [u8; 12usize] = b"OfxPropName\0";
+pub const kOfxPropVersion: &'static [u8; 15usize] = b"OfxPropVersion\0";
+pub const kOfxPropVersionLabel: &'static [u8; 20usize] = b"OfxPropVersionLabel\0";
+pub const kOfxPropPluginDescription: &'static [u8; 25usize] = b"OfxPropPluginDescription\0";
+pub const kOfxPropLabel: &'static [u8; 13usize] = b"OfxPropLabel\0";
+pub const kOfxPropIcon: &'static [u8; 12usize] = b"OfxPropIcon\0";
+pub const kOfxPropShortLabel: &'static [u8; 18usize] = b"OfxPropShortLabel\0";
+pub const kOfxPropLongLabel: &'static [u8; 17usize] = b"OfxPropLongLabel\0";
+pub const kOfxPropChangeReason: &'static [u8; 20usize] = b"OfxPropChangeReason\0";
+pub const kOfxPropEffectInstance: &'static [u8; 22usize] = b"OfxPropEffectInstance\0";
+pub const kOfxPropHostOSHandle: &'static [u8; 20usize] = b"OfxPropHostOSHandle\0";
+pub const kOfxChangeUserEdited: &'static [u8; 20usize] = b"OfxChangeUserEdited\0";
+pub const kOfxChangePluginEdited: &'static [u8; 22usize] = b"OfxChangePluginEdited\0";
+pub const kOfxChangeTime: &'static [u8; 14usize] = b"OfxChangeTime\0";
+pub const kOfxFlagInfiniteMax: u32 = 2147483647;
+pub const kOfxFlagInfiniteMin: i32 = -2147483648;
+pub const kOfxBitDepthNone: &'static [u8; 16usize] = b"OfxBitDepthNone\0";
+pub const kOfxBitDepthByte: &'static [u8; 16usize] = b"OfxBitDepthByte\0";
+pub const kOfxBitDepthShort: &'static [u8; 17usize] = b"OfxBitDepthShort\0";
+pub const kOfxBitDepthHalf: &'static [u8; 16usize] = b"OfxBitDepthHalf\0";
+pub const kOfxBitDepthFloat: &'static [u8; 17usize] = b"OfxBitDepthFloat\0";
+pub const kOfxStatOK: u32 = 0;
+pub const kOfxPropertySuite: &'static [u8; 17usize] = b"OfxPropertySuite\0";
+pub const kOfxParameterSuite: &'static [u8; 18usize] = b"OfxParameterSuite\0";
+pub const kOfxTypeParameter: &'static [u8; 17usize] = b"OfxTypeParameter\0";
+pub const kOfxTypeParameterInstance: &'static [u8; 25usize] = b"OfxTypeParameterInstance\0";
+pub const kOfxParamTypeInteger: &'static [u8; 20usize] = b"OfxParamTypeInteger\0";
+pub const kOfxParamTypeDouble: &'static [u8; 19usize] = b"OfxParamTypeDouble\0";
+pub const kOfxParamTypeBoolean: &'static [u8; 20usize] = b"OfxParamTypeBoolean\0";
+pub const kOfxParamTypeChoice: &'static [u8; 19usize] = b"OfxParamTypeChoice\0";
+pub const kOfxParamTypeRGBA: &'static [u8; 17usize] = b"OfxParamTypeRGBA\0";
+pub const kOfxParamTypeRGB: &'static [u8; 16usize] = b"OfxParamTypeRGB\0";
+pub const kOfxParamTypeDouble2D: &'static [u8; 21usize] = b"OfxParamTypeDouble2D\0";
+pub const kOfxParamTypeInteger2D: &'static [u8; 22usize] = b"OfxParamTypeInteger2D\0";
+pub const kOfxParamTypeDouble3D: &'static [u8; 21usize] = b"OfxParamTypeDouble3D\0";
+pub const kOfxParamTypeInteger3D: &'static [u8; 22usize] = b"OfxParamTypeInteger3D\0";
+pub const kOfxParamTypeString: &'static [u8; 19usize] = b"OfxParamTypeString\0";
+pub const kOfxParamTypeCustom: &'static [u8; 19usize] = b"OfxParamTypeCustom\0";
+pub const kOfxParamTypeGroup: &'static [u8; 18usize] = b"OfxParamTypeGroup\0";
+pub const kOfxParamTypePage: &'static [u8; 17usize] = b"OfxParamTypePage\0";
+pub const kOfxParamTypePushButton: &'static [u8; 23usize] = b"OfxParamTypePushButton\0";
+pub const kOfxParamHostPropSupportsCustomAnimation: &'static [u8; 40usize] =
+    b"OfxParamHostPropSupportsCustomAnimation\0";
+pub const kOfxParamHostPropSupportsStringAnimation: &'static [u8; 40usize] =
+    b"OfxParamHostPropSupportsStringAnimation\0";
+pub const kOfxParamHostPropSupportsBooleanAnimation: &'static [u8; 41usize] =
+    b"OfxParamHostPropSupportsBooleanAnimation\0";
+pub const kOfxParamHostPropSupportsChoiceAnimation: &'static [u8; 40usize] =
+    b"OfxParamHostPropSupportsChoiceAnimation\0";
+pub const kOfxParamHostPropSupportsCustomInteract: &'static [u8; 39usize] =
+    b"OfxParamHostPropSupportsCustomInteract\0";
+pub const kOfxParamHostPropMaxParameters: &'static [u8; 30usize] =
+    b"OfxParamHostPropMaxParameters\0";
+pub const kOfxParamHostPropMaxPages: &'static [u8; 25usize] = b"OfxParamHostPropMaxPages\0";
+pub const kOfxParamHostPropPageRowColumnCount: &'static [u8; 35usize] =
+    b"OfxParamHostPropPageRowColumnCount\0";
+pub const kOfxParamPageSkipRow: &'static [u8; 20usize] = b"OfxParamPageSkipRow\0";
+pub const kOfxParamPageSkipColumn: &'static [u8; 23usize] = b"OfxParamPageSkipColumn\0";
+pub const kOfxParamPropInteractV1: &'static [u8; 23usize] = b"OfxParamPropInteractV1\0";
+pub const kOfxParamPropInteractSize: &'static [u8; 25usize] = b"OfxParamPropInteractSize\0";
+pub const kOfxParamPropInteractSizeAspect: &'static [u8; 31usize] =
+    b"OfxParamPropInteractSizeAspect\0";
+pub const kOfxParamPropInteractMinimumSize: &'static [u8; 32usize] =
+    b"OfxParamPropInteractMinimumSize\0";
+pub const kOfxParamPropInteractPreferedSize: &'static [u8; 33usize] =
+    b"OfxParamPropInteractPreferedSize\0";
+pub const kOfxParamPropType: &'static [u8; 17usize] = b"OfxParamPropType\0";
+pub const kOfxParamPropAnimates: &'static [u8; 21usize] = b"OfxParamPropAnimates\0";
+pub const kOfxParamPropCanUndo: &'static [u8; 20usize] = b"OfxParamPropCanUndo\0";
+pub const kOfxPropParamSetNeedsSyncing: &'static [u8; 28usize] = b"OfxPropParamSetNeedsSyncing\0";
+pub const kOfxParamPropIsAnimating: &'static [u8; 24usize] = b"OfxParamPropIsAnimating\0";
+pub const kOfxParamPropPluginMayWrite: &'static [u8; 27usize] = b"OfxParamPropPluginMayWrite\0";
+pub const kOfxParamPropPersistent: &'static [u8; 23usize] = b"OfxParamPropPersistant\0";
+pub const kOfxParamPropEvaluateOnChange: &'static [u8; 29usize] = b"OfxParamPropEvaluateOnChange\0";
+pub const kOfxParamPropSecret: &'static [u8; 19usize] = b"OfxParamPropSecret\0";
+pub const kOfxParamPropScriptName: &'static [u8; 23usize] = b"OfxParamPropScriptName\0";
+pub const kOfxParamPropCacheInvalidation: &'static [u8; 30usize] =
+    b"OfxParamPropCacheInvalidation\0";
+pub const kOfxParamInvalidateValueChange: &'static [u8; 30usize] =
+    b"OfxParamInvalidateValueChange\0";
+pub const kOfxParamInvalidateValueChangeToEnd: &'static [u8; 35usize] =
+    b"OfxParamInvalidateValueChangeToEnd\0";
+pub const kOfxParamInvalidateAll: &'static [u8; 22usize] = b"OfxParamInvalidateAll\0";
+pub const kOfxParamPropHint: &'static [u8; 17usize] = b"OfxParamPropHint\0";
+pub const kOfxParamPropDefault: &'static [u8; 20usize] = b"OfxParamPropDefault\0";
+pub const kOfxParamPropDoubleType: &'static [u8; 23usize] = b"OfxParamPropDoubleType\0";
+pub const kOfxParamDoubleTypePlain: &'static [u8; 24usize] = b"OfxParamDoubleTypePlain\0";
+pub const kOfxParamDoubleTypeScale: &'static [u8; 24usize] = b"OfxParamDoubleTypeScale\0";
+pub const kOfxParamDoubleTypeAngle: &'static [u8; 24usize] = b"OfxParamDoubleTypeAngle\0";
+pub const kOfxParamDoubleTypeTime: &'static [u8; 23usize] = b"OfxParamDoubleTypeTime\0";
+pub const kOfxParamDoubleTypeAbsoluteTime: &'static [u8; 31usize] =
+    b"OfxParamDoubleTypeAbsoluteTime\0";
+pub const kOfxParamDoubleTypeX: &'static [u8; 20usize] = b"OfxParamDoubleTypeX\0";
+pub const kOfxParamDoubleTypeY: &'static [u8; 20usize] = b"OfxParamDoubleTypeY\0";
+pub const kOfxParamDoubleTypeXAbsolute: &'static [u8; 28usize] = b"OfxParamDoubleTypeXAbsolute\0";
+pub const kOfxParamDoubleTypeYAbsolute: &'static [u8; 28usize] = b"OfxParamDoubleTypeYAbsolute\0";
+pub const kOfxParamDoubleTypeXY: &'static [u8; 21usize] = b"OfxParamDoubleTypeXY\0";
+pub const kOfxParamDoubleTypeXYAbsolute: &'static [u8; 29usize] = b"OfxParamDoubleTypeXYAbsolute\0";
+pub const kOfxParamPropDefaultCoordinateSystem: &'static [u8; 36usize] =
+    b"OfxParamPropDefaultCoordinateSystem\0";
+pub const kOfxParamCoordinatesCanonical: &'static [u8; 29usize] = b"OfxParamCoordinatesCanonical\0";
+pub const kOfxParamCoordinatesNormalised: &'static [u8; 30usize] =
+    b"OfxParamCoordinatesNormalised\0";
+pub const kOfxParamPropHasHostOverlayHandle: &'static [u8; 33usize] =
+    b"OfxParamPropHasHostOverlayHandle\0";
+pub const kOfxParamPropUseHostOverlayHandle: &'static [u8; 34usize] =
+    b"kOfxParamPropUseHostOverlayHandle\0";
+pub const kOfxParamPropShowTimeMarker: &'static [u8; 27usize] = b"OfxParamPropShowTimeMarker\0";
+pub const kOfxPluginPropParamPageOrder: &'static [u8; 28usize] = b"OfxPluginPropParamPageOrder\0";
+pub const kOfxParamPropPageChild: &'static [u8; 22usize] = b"OfxParamPropPageChild\0";
+pub const kOfxParamPropParent: &'static [u8; 19usize] = b"OfxParamPropParent\0";
+pub const kOfxParamPropGroupOpen: &'static [u8; 22usize] = b"OfxParamPropGroupOpen\0";
+pub const kOfxParamPropEnabled: &'static [u8; 20usize] = b"OfxParamPropEnabled\0";
+pub const kOfxParamPropDataPtr: &'static [u8; 20usize] = b"OfxParamPropDataPtr\0";
+pub const kOfxParamPropChoiceOption: &'static [u8; 25usize] = b"OfxParamPropChoiceOption\0";
+pub const kOfxParamPropMin: &'static [u8; 16usize] = b"OfxParamPropMin\0";
+pub const kOfxParamPropMax: &'static [u8; 16usize] = b"OfxParamPropMax\0";
+pub const kOfxParamPropDisplayMin: &'static [u8; 23usize] = b"OfxParamPropDisplayMin\0";
+pub const kOfxParamPropDisplayMax: &'static [u8; 23usize] = b"OfxParamPropDisplayMax\0";
+pub const kOfxParamPropIncrement: &'static [u8; 22usize] = b"OfxParamPropIncrement\0";
+pub const kOfxParamPropDigits: &'static [u8; 19usize] = b"OfxParamPropDigits\0";
+pub const kOfxParamPropDimensionLabel: &'static [u8; 27usize] = b"OfxParamPropDimensionLabel\0";
+pub const kOfxParamPropIsAutoKeying: &'static [u8; 25usize] = b"OfxParamPropIsAutoKeying\0";
+pub const kOfxParamPropCustomInterpCallbackV1: &'static [u8; 29usize] =
+    b"OfxParamPropCustomCallbackV1\0";
+pub const kOfxParamPropStringMode: &'static [u8; 23usize] = b"OfxParamPropStringMode\0";
+pub const kOfxParamPropStringFilePathExists: &'static [u8; 33usize] =
+    b"OfxParamPropStringFilePathExists\0";
+pub const kOfxParamStringIsSingleLine: &'static [u8; 27usize] = b"OfxParamStringIsSingleLine\0";
+pub const kOfxParamStringIsMultiLine: &'static [u8; 26usize] = b"OfxParamStringIsMultiLine\0";
+pub const kOfxParamStringIsFilePath: &'static [u8; 25usize] = b"OfxParamStringIsFilePath\0";
+pub const kOfxParamStringIsDirectoryPath: &'static [u8; 30usize] =
+    b"OfxParamStringIsDirectoryPath\0";
+pub const kOfxParamStringIsLabel: &'static [u8; 22usize] = b"OfxParamStringIsLabel\0";
+pub const kOfxParamStringIsRichTextFormat: &'static [u8; 31usize] =
+    b"OfxParamStringIsRichTextFormat\0";
+pub const kOfxParamPropCustomValue: &'static [u8; 24usize] = b"OfxParamPropCustomValue\0";
+pub const kOfxParamPropInterpolationTime: &'static [u8; 30usize] =
+    b"OfxParamPropInterpolationTime\0";
+pub const kOfxParamPropInterpolationAmount: &'static [u8; 32usize] =
+    b"OfxParamPropInterpolationAmount\0";
+pub const kOfxInteractSuite: &'static [u8; 17usize] = b"OfxInteractSuite\0";
+pub const kOfxInteractPropSlaveToParam: &'static [u8; 28usize] = b"OfxInteractPropSlaveToParam\0";
+pub const kOfxInteractPropPixelScale: &'static [u8; 26usize] = b"OfxInteractPropPixelScale\0";
+pub const kOfxInteractPropBackgroundColour: &'static [u8; 32usize] =
+    b"OfxInteractPropBackgroundColour\0";
+pub const kOfxInteractPropSuggestedColour: &'static [u8; 31usize] =
+    b"OfxInteractPropSuggestedColour\0";
+pub const kOfxInteractPropPenPosition: &'static [u8; 27usize] = b"OfxInteractPropPenPosition\0";
+pub const kOfxInteractPropPenViewportPosition: &'static [u8; 35usize] =
+    b"OfxInteractPropPenViewportPosition\0";
+pub const kOfxInteractPropPenPressure: &'static [u8; 27usize] = b"OfxInteractPropPenPressure\0";
+pub const kOfxInteractPropBitDepth: &'static [u8; 24usize] = b"OfxInteractPropBitDepth\0";
+pub const kOfxInteractPropHasAlpha: &'static [u8; 24usize] = b"OfxInteractPropHasAlpha\0";
+pub const kOfxActionDescribeInteract: &'static [u8; 18usize] = b"OfxActionDescribe\0";
+pub const kOfxActionCreateInstanceInteract: &'static [u8; 24usize] = b"OfxActionCreateInstance\0";
+pub const kOfxActionDestroyInstanceInteract: &'static [u8; 25usize] = b"OfxActionDestroyInstance\0";
+pub const kOfxInteractActionDraw: &'static [u8; 22usize] = b"OfxInteractActionDraw\0";
+pub const kOfxInteractActionPenMotion: &'static [u8; 27usize] = b"OfxInteractActionPenMotion\0";
+pub const kOfxInteractActionPenDown: &'static [u8; 25usize] = b"OfxInteractActionPenDown\0";
+pub const kOfxInteractActionPenUp: &'static [u8; 23usize] = b"OfxInteractActionPenUp\0";
+pub const kOfxInteractActionKeyDown: &'static [u8; 25usize] = b"OfxInteractActionKeyDown\0";
+pub const kOfxInteractActionKeyUp: &'static [u8; 23usize] = b"OfxInteractActionKeyUp\0";
+pub const kOfxInteractActionKeyRepeat: &'static [u8; 27usize] = b"OfxInteractActionKeyRepeat\0";
+pub const kOfxInteractActionGainFocus: &'static [u8; 27usize] = b"OfxInteractActionGainFocus\0";
+pub const kOfxInteractActionLoseFocus: &'static [u8; 27usize] = b"OfxInteractActionLoseFocus\0";
+pub const kOfxMessageSuite: &'static [u8; 16usize] = b"OfxMessageSuite\0";
+pub const kOfxMessageFatal: &'static [u8; 16usize] = b"OfxMessageFatal\0";
+pub const kOfxMessageError: &'static [u8; 16usize] = b"OfxMessageError\0";
+pub const kOfxMessageWarning: &'static [u8; 18usize] = b"OfxMessageWarning\0";
+pub const kOfxMessageMessage: &'static [u8; 18usize] = b"OfxMessageMessage\0";
+pub const kOfxMessageLog: &'static [u8; 14usize] = b"OfxMessageLog\0";
+pub const kOfxMessageQuestion: &'static [u8; 19usize] = b"OfxMessageQuestion\0";
+pub const kOfxMemorySuite: &'static [u8; 15usize] = b"OfxMemorySuite\0";
+pub const kOfxMultiThreadSuite: &'static [u8; 20usize] = b"OfxMultiThreadSuite\0";
+pub const kOfxImageEffectPluginApi: &'static [u8; 24usize] = b"OfxImageEffectPluginAPI\0";
+pub const kOfxImageEffectPluginApiVersion: u32 = 1;
+pub const kOfxImageComponentNone: &'static [u8; 22usize] = b"OfxImageComponentNone\0";
+pub const kOfxImageComponentRGBA: &'static [u8; 22usize] = b"OfxImageComponentRGBA\0";
+pub const kOfxImageComponentRGB: &'static [u8; 21usize] = b"OfxImageComponentRGB\0";
+pub const kOfxImageComponentAlpha: &'static [u8; 23usize] = b"OfxImageComponentAlpha\0";
+pub const kOfxImageEffectContextGenerator: &'static [u8; 31usize] =
+    b"OfxImageEffectContextGenerator\0";
+pub const kOfxImageEffectContextFilter: &'static [u8; 28usize] = b"OfxImageEffectContextFilter\0";
+pub const kOfxImageEffectContextTransition: &'static [u8; 32usize] =
+    b"OfxImageEffectContextTransition\0";
+pub const kOfxImageEffectContextPaint: &'static [u8; 27usize] = b"OfxImageEffectContextPaint\0";
+pub const kOfxImageEffectContextGeneral: &'static [u8; 29usize] = b"OfxImageEffectContextGeneral\0";
+pub const kOfxImageEffectContextRetimer: &'static [u8; 29usize] = b"OfxImageEffectContextRetimer\0";
+pub const kOfxTypeImageEffectHost: &'static [u8; 23usize] = b"OfxTypeImageEffectHost\0";
+pub const kOfxTypeImageEffect: &'static [u8; 19usize] = b"OfxTypeImageEffect\0";
+pub const kOfxTypeImageEffectInstance: &'static [u8; 27usize] = b"OfxTypeImageEffectInstance\0";
+pub const kOfxTypeClip: &'static [u8; 12usize] = b"OfxTypeClip\0";
+pub const kOfxTypeImage: &'static [u8; 13usize] = b"OfxTypeImage\0";
+pub const kOfxImageEffectActionGetRegionOfDefinition: &'static [u8; 42usize] =
+    b"OfxImageEffectActionGetRegionOfDefinition\0";
+pub const kOfxImageEffectActionGetRegionsOfInterest: &'static [u8; 41usize] =
+    b"OfxImageEffectActionGetRegionsOfInterest\0";
+pub const kOfxImageEffectActionGetTimeDomain: &'static [u8; 34usize] =
+    b"OfxImageEffectActionGetTimeDomain\0";
+pub const kOfxImageEffectActionGetFramesNeeded: &'static [u8; 36usize] =
+    b"OfxImageEffectActionGetFramesNeeded\0";
+pub const kOfxImageEffectActionGetClipPreferences: &'static [u8; 39usize] =
+    b"OfxImageEffectActionGetClipPreferences\0";
+pub const kOfxImageEffectActionIsIdentity: &'static [u8; 31usize] =
+    b"OfxImageEffectActionIsIdentity\0";
+pub const kOfxImageEffectActionRender: &'static [u8; 27usize] = b"OfxImageEffectActionRender\0";
+pub const kOfxImageEffectActionBeginSequenceRender: &'static [u8; 40usize] =
+    b"OfxImageEffectActionBeginSequenceRender\0";
+pub const kOfxImageEffectActionEndSequenceRender: &'static [u8; 38usize] =
+    b"OfxImageEffectActionEndSequenceRender\0";
+pub const kOfxImageEffectActionDescribeInContext: &'static [u8; 38usize] =
+    b"OfxImageEffectActionDescribeInContext\0";
+pub const kOfxImageEffectPropSupportedContexts: &'static [u8; 36usize] =
+    b"OfxImageEffectPropSupportedContexts\0";
+pub const kOfxImageEffectPropPluginHandle: &'static [u8; 31usize] =
+    b"OfxImageEffectPropPluginHandle\0";
+pub const kOfxImageEffectHostPropIsBackground: &'static [u8; 35usize] =
+    b"OfxImageEffectHostPropIsBackground\0";
+pub const kOfxImageEffectPluginPropSingleInstance: &'static [u8; 39usize] =
+    b"OfxImageEffectPluginPropSingleInstance\0";
+pub const kOfxImageEffectPluginRenderThreadSafety: &'static [u8; 39usize] =
+    b"OfxImageEffectPluginRenderThreadSafety\0";
+pub const kOfxImageEffectRenderUnsafe: &'static [u8; 27usize] = b"OfxImageEffectRenderUnsafe\0";
+pub const kOfxImageEffectRenderInstanceSafe: &'static [u8; 33usize] =
+    b"OfxImageEffectRenderInstanceSafe\0";
+pub const kOfxImageEffectRenderFullySafe: &'static [u8; 30usize] =
+    b"OfxImageEffectRenderFullySafe\0";
+pub const kOfxImageEffectPluginPropHostFrameThreading: &'static [u8; 43usize] =
+    b"OfxImageEffectPluginPropHostFrameThreading\0";
+pub const kOfxImageEffectPropSupportsMultipleClipDepths: &'static [u8; 37usize] =
+    b"OfxImageEffectPropMultipleClipDepths\0";
+pub const kOfxImageEffectPropSupportsMultipleClipPARs: &'static [u8; 43usize] =
+    b"OfxImageEffectPropSupportsMultipleClipPARs\0";
+pub const kOfxImageEffectPropClipPreferencesSlaveParam: &'static [u8; 44usize] =
+    b"OfxImageEffectPropClipPreferencesSlaveParam\0";
+pub const kOfxImageEffectPropSetableFrameRate: &'static [u8; 35usize] =
+    b"OfxImageEffectPropSetableFrameRate\0";
+pub const kOfxImageEffectPropSetableFielding: &'static [u8; 34usize] =
+    b"OfxImageEffectPropSetableFielding\0";
+pub const kOfxImageEffectInstancePropSequentialRender: &'static [u8; 43usize] =
+    b"OfxImageEffectInstancePropSequentialRender\0";
+pub const kOfxImageEffectPropSequentialRenderStatus: &'static [u8; 41usize] =
+    b"OfxImageEffectPropSequentialRenderStatus\0";
+pub const kOfxHostNativeOriginBottomLeft: &'static [u8; 46usize] =
+    b"kOfxImageEffectHostPropNativeOriginBottomLeft\0";
+pub const kOfxHostNativeOriginTopLeft: &'static [u8; 43usize] =
+    b"kOfxImageEffectHostPropNativeOriginTopLeft\0";
+pub const kOfxHostNativeOriginCenter: &'static [u8; 42usize] =
+    b"kOfxImageEffectHostPropNativeOriginCenter\0";
+pub const kOfxImageEffectHostPropNativeOrigin: &'static [u8; 35usize] =
+    b"OfxImageEffectHostPropNativeOrigin\0";
+pub const kOfxImageEffectPropInteractiveRenderStatus: &'static [u8; 42usize] =
+    b"OfxImageEffectPropInteractiveRenderStatus\0";
+pub const kOfxImageEffectPluginPropGrouping: &'static [u8; 33usize] =
+    b"OfxImageEffectPluginPropGrouping\0";
+pub const kOfxImageEffectPropSupportsOverlays: &'static [u8; 35usize] =
+    b"OfxImageEffectPropSupportsOverlays\0";
+pub const kOfxImageEffectPluginPropOverlayInteractV1: &'static [u8; 42usize] =
+    b"OfxImageEffectPluginPropOverlayInteractV1\0";
+pub const kOfxImageEffectPropSupportsMultiResolution: &'static [u8; 42usize] =
+    b"OfxImageEffectPropSupportsMultiResolution\0";
+pub const kOfxImageEffectPropSupportsTiles: &'static [u8; 32usize] =
+    b"OfxImageEffectPropSupportsTiles\0";
+pub const kOfxImageEffectPropTemporalClipAccess: &'static [u8; 37usize] =
+    b"OfxImageEffectPropTemporalClipAccess\0";
+pub const kOfxImageEffectPropContext: &'static [u8; 26usize] = b"OfxImageEffectPropContext\0";
+pub const kOfxImageEffectPropPixelDepth: &'static [u8; 29usize] = b"OfxImageEffectPropPixelDepth\0";
+pub const kOfxImageEffectPropComponents: &'static [u8; 29usize] = b"OfxImageEffectPropComponents\0";
+pub const kOfxImagePropUniqueIdentifier: &'static [u8; 29usize] = b"OfxImagePropUniqueIdentifier\0";
+pub const kOfxImageClipPropContinuousSamples: &'static [u8; 34usize] =
+    b"OfxImageClipPropContinuousSamples\0";
+pub const kOfxImageClipPropUnmappedPixelDepth: &'static [u8; 35usize] =
+    b"OfxImageClipPropUnmappedPixelDepth\0";
+pub const kOfxImageClipPropUnmappedComponents: &'static [u8; 35usize] =
+    b"OfxImageClipPropUnmappedComponents\0";
+pub const kOfxImageEffectPropPreMultiplication: &'static [u8; 36usize] =
+    b"OfxImageEffectPropPreMultiplication\0";
+pub const kOfxImageOpaque: &'static [u8; 15usize] = b"OfxImageOpaque\0";
+pub const kOfxImagePreMultiplied: &'static [u8; 27usize] = b"OfxImageAlphaPremultiplied\0";
+pub const kOfxImageUnPreMultiplied: &'static [u8; 29usize] = b"OfxImageAlphaUnPremultiplied\0";
+pub const kOfxImageEffectPropSupportedPixelDepths: &'static [u8; 39usize] =
+    b"OfxImageEffectPropSupportedPixelDepths\0";
+pub const kOfxImageEffectPropSupportedComponents: &'static [u8; 38usize] =
+    b"OfxImageEffectPropSupportedComponents\0";
+pub const kOfxImageClipPropOptional: &'static [u8; 25usize] = b"OfxImageClipPropOptional\0";
+pub const kOfxImageClipPropIsMask: &'static [u8; 23usize] = b"OfxImageClipPropIsMask\0";
+pub const kOfxImagePropPixelAspectRatio: &'static [u8; 29usize] = b"OfxImagePropPixelAspectRatio\0";
+pub const kOfxImageEffectPropFrameRate: &'static [u8; 28usize] = b"OfxImageEffectPropFrameRate\0";
+pub const kOfxImageEffectPropUnmappedFrameRate: &'static [u8; 36usize] =
+    b"OfxImageEffectPropUnmappedFrameRate\0";
+pub const kOfxImageEffectPropFrameStep: &'static [u8; 28usize] = b"OfxImageEffectPropFrameStep\0";
+pub const kOfxImageEffectPropFrameRange: &'static [u8; 29usize] = b"OfxImageEffectPropFrameRange\0";
+pub const kOfxImageEffectPropUnmappedFrameRange: &'static [u8; 37usize] =
+    b"OfxImageEffectPropUnmappedFrameRange\0";
+pub const kOfxImageClipPropConnected: &'static [u8; 26usize] = b"OfxImageClipPropConnected\0";
+pub const kOfxImageEffectFrameVarying: &'static [u8; 27usize] = b"OfxImageEffectFrameVarying\0";
+pub const kOfxImageEffectPropRenderScale: &'static [u8; 30usize] =
+    b"OfxImageEffectPropRenderScale\0";
+pub const kOfxImageEffectPropRenderQualityDraft: &'static [u8; 37usize] =
+    b"OfxImageEffectPropRenderQualityDraft\0";
+pub const kOfxImageEffectPropProjectExtent: &'static [u8; 32usize] =
+    b"OfxImageEffectPropProjectExtent\0";
+pub const kOfxImageEffectPropProjectSize: &'static [u8; 30usize] =
+    b"OfxImageEffectPropProjectSize\0";
+pub const kOfxImageEffectPropProjectOffset: &'static [u8; 32usize] =
+    b"OfxImageEffectPropProjectOffset\0";
+pub const kOfxImageEffectPropProjectPixelAspectRatio: &'static [u8; 35usize] =
+    b"OfxImageEffectPropPixelAspectRatio\0";
+pub const kOfxImageEffectInstancePropEffectDuration: &'static [u8; 41usize] =
+    b"OfxImageEffectInstancePropEffectDuration\0";
+pub const kOfxImageClipPropFieldOrder: &'static [u8; 27usize] = b"OfxImageClipPropFieldOrder\0";
+pub const kOfxImagePropData: &'static [u8; 17usize] = b"OfxImagePropData\0";
+pub const kOfxImagePropBounds: &'static [u8; 19usize] = b"OfxImagePropBounds\0";
+pub const kOfxImagePropRegionOfDefinition: &'static [u8; 31usize] =
+    b"OfxImagePropRegionOfDefinition\0";
+pub const kOfxImagePropRowBytes: &'static [u8; 21usize] = b"OfxImagePropRowBytes\0";
+pub const kOfxImagePropField: &'static [u8; 18usize] = b"OfxImagePropField\0";
+pub const kOfxImageEffectPluginPropFieldRenderTwiceAlways: &'static [u8; 47usize] =
+    b"OfxImageEffectPluginPropFieldRenderTwiceAlways\0";
+pub const kOfxImageClipPropFieldExtraction: &'static [u8; 32usize] =
+    b"OfxImageClipPropFieldExtraction\0";
+pub const kOfxImageEffectPropFieldToRender: &'static [u8; 32usize] =
+    b"OfxImageEffectPropFieldToRender\0";
+pub const kOfxImageEffectPropRegionOfDefinition: &'static [u8; 37usize] =
+    b"OfxImageEffectPropRegionOfDefinition\0";
+pub const kOfxImageEffectPropRegionOfInterest: &'static [u8; 35usize] =
+    b"OfxImageEffectPropRegionOfInterest\0";
+pub const kOfxImageEffectPropRenderWindow: &'static [u8; 31usize] =
+    b"OfxImageEffectPropRenderWindow\0";
+pub const kOfxImageFieldNone: &'static [u8; 13usize] = b"OfxFieldNone\0";
+pub const kOfxImageFieldLower: &'static [u8; 14usize] = b"OfxFieldLower\0";
+pub const kOfxImageFieldUpper: &'static [u8; 14usize] = b"OfxFieldUpper\0";
+pub const kOfxImageFieldBoth: &'static [u8; 13usize] = b"OfxFieldBoth\0";
+pub const kOfxImageFieldSingle: &'static [u8; 15usize] = b"OfxFieldSingle\0";
+pub const kOfxImageFieldDoubled: &'static [u8; 16usize] = b"OfxFieldDoubled\0";
+pub const kOfxImageEffectOutputClipName: &'static [u8; 7usize] = b"Output\0";
+pub const kOfxImageEffectSimpleSourceClipName: &'static [u8; 7usize] = b"Source\0";
+pub const kOfxImageEffectTransitionSourceFromClipName: &'static [u8; 11usize] = b"SourceFrom\0";
+pub const kOfxImageEffectTransitionSourceToClipName: &'static [u8; 9usize] = b"SourceTo\0";
+pub const kOfxImageEffectTransitionParamName: &'static [u8; 11usize] = b"Transition\0";
+pub const kOfxImageEffectRetimerParamName: &'static [u8; 11usize] = b"SourceTime\0";
+pub const kOfxImageEffectSuite: &'static [u8; 20usize] = b"OfxImageEffectSuite\0";
+pub const kOfxImageComponentYUVA: &'static [u8; 22usize] = b"OfxImageComponentYUVA\0";
+pub const kOfxImageEffectPropInAnalysis: &'static [u8; 29usize] = b"OfxImageEffectPropInAnalysis\0";
+pub const kOfxInteractPropViewportSize: &'static [u8; 24usize] = b"OfxInteractPropViewport\0";
+pub const kOfxParamDoubleTypeNormalisedX: &'static [u8; 30usize] =
+    b"OfxParamDoubleTypeNormalisedX\0";
+pub const kOfxParamDoubleTypeNormalisedY: &'static [u8; 30usize] =
+    b"OfxParamDoubleTypeNormalisedY\0";
+pub const kOfxParamDoubleTypeNormalisedXAbsolute: &'static [u8; 38usize] =
+    b"OfxParamDoubleTypeNormalisedXAbsolute\0";
+pub const kOfxParamDoubleTypeNormalisedYAbsolute: &'static [u8; 38usize] =
+    b"OfxParamDoubleTypeNormalisedYAbsolute\0";
+pub const kOfxParamDoubleTypeNormalisedXY: &'static [u8; 31usize] =
+    b"OfxParamDoubleTypeNormalisedXY\0";
+pub const kOfxParamDoubleTypeNormalisedXYAbsolute: &'static [u8; 39usize] =
+    b"OfxParamDoubleTypeNormalisedXYAbsolute\0";
+pub const kOfxProgressSuite: &'static [u8; 17usize] = b"OfxProgressSuite\0";
+pub const kOfxPropKeySym: &'static [u8; 15usize] = b"kOfxPropKeySym\0";
+pub const kOfxPropKeyString: &'static [u8; 18usize] = b"kOfxPropKeyString\0";
+pub const kOfxKey_Unknown: u32 = 0;
+pub const kOfxKey_BackSpace: u32 = 65288;
+pub const kOfxKey_Tab: u32 = 65289;
+pub const kOfxKey_Linefeed: u32 = 65290;
+pub const kOfxKey_Clear: u32 = 65291;
+pub const kOfxKey_Return: u32 = 65293;
+pub const kOfxKey_Pause: u32 = 65299;
+pub const kOfxKey_Scroll_Lock: u32 = 65300;
+pub const kOfxKey_Sys_Req: u32 = 65301;
+pub const kOfxKey_Escape: u32 = 65307;
+pub const kOfxKey_Delete: u32 = 65535;
+pub const kOfxKey_Multi_key: u32 = 65312;
+pub const kOfxKey_SingleCandidate: u32 = 65340;
+pub const kOfxKey_MultipleCandidate: u32 = 65341;
+pub const kOfxKey_PreviousCandidate: u32 = 65342;
+pub const kOfxKey_Kanji: u32 = 65313;
+pub const kOfxKey_Muhenkan: u32 = 65314;
+pub const kOfxKey_Henkan_Mode: u32 = 65315;
+pub const kOfxKey_Henkan: u32 = 65315;
+pub const kOfxKey_Romaji: u32 = 65316;
+pub const kOfxKey_Hiragana: u32 = 65317;
+pub const kOfxKey_Katakana: u32 = 65318;
+pub const kOfxKey_Hiragana_Katakana: u32 = 65319;
+pub const kOfxKey_Zenkaku: u32 = 65320;
+pub const kOfxKey_Hankaku: u32 = 65321;
+pub const kOfxKey_Zenkaku_Hankaku: u32 = 65322;
+pub const kOfxKey_Touroku: u32 = 65323;
+pub const kOfxKey_Massyo: u32 = 65324;
+pub const kOfxKey_Kana_Lock: u32 = 65325;
+pub const kOfxKey_Kana_Shift: u32 = 65326;
+pub const kOfxKey_Eisu_Shift: u32 = 65327;
+pub const kOfxKey_Eisu_toggle: u32 = 65328;
+pub const kOfxKey_Zen_Koho: u32 = 65341;
+pub const kOfxKey_Mae_Koho: u32 = 65342;
+pub const kOfxKey_Home: u32 = 65360;
+pub const kOfxKey_Left: u32 = 65361;
+pub const kOfxKey_Up: u32 = 65362;
+pub const kOfxKey_Right: u32 = 65363;
+pub const kOfxKey_Down: u32 = 65364;
+pub const kOfxKey_Prior: u32 = 65365;
+pub const kOfxKey_Page_Up: u32 = 65365;
+pub const kOfxKey_Next: u32 = 65366;
+pub const kOfxKey_Page_Down: u32 = 65366;
+pub const kOfxKey_End: u32 = 65367;
+pub const kOfxKey_Begin: u32 = 65368;
+pub const kOfxKey_Select: u32 = 65376;
+pub const kOfxKey_Print: u32 = 65377;
+pub const kOfxKey_Execute: u32 = 65378;
+pub const kOfxKey_Insert: u32 = 65379;
+pub const kOfxKey_Undo: u32 = 65381;
+pub const kOfxKey_Redo: u32 = 65382;
+pub const kOfxKey_Menu: u32 = 65383;
+pub const kOfxKey_Find: u32 = 65384;
+pub const kOfxKey_Cancel: u32 = 65385;
+pub const kOfxKey_Help: u32 = 65386;
+pub const kOfxKey_Break: u32 = 65387;
+pub const kOfxKey_Mode_switch: u32 = 65406;
+pub const kOfxKey_script_switch: u32 = 65406;
+pub const kOfxKey_Num_Lock: u32 = 65407;
+pub const kOfxKey_KP_Space: u32 = 65408;
+pub const kOfxKey_KP_Tab: u32 = 65417;
+pub const kOfxKey_KP_Enter: u32 = 65421;
+pub const kOfxKey_KP_F1: u32 = 65425;
+pub const kOfxKey_KP_F2: u32 = 65426;
+pub const kOfxKey_KP_F3: u32 = 65427;
+pub const kOfxKey_KP_F4: u32 = 65428;
+pub const kOfxKey_KP_Home: u32 = 65429;
+pub const kOfxKey_KP_Left: u32 = 65430;
+pub const kOfxKey_KP_Up: u32 = 65431;
+pub const kOfxKey_KP_Right: u32 = 65432;
+pub const kOfxKey_KP_Down: u32 = 65433;
+pub const kOfxKey_KP_Prior: u32 = 65434;
+pub const kOfxKey_KP_Page_Up: u32 = 65434;
+pub const kOfxKey_KP_Next: u32 = 65435;
+pub const kOfxKey_KP_Page_Down: u32 = 65435;
+pub const kOfxKey_KP_End: u32 = 65436;
+pub const kOfxKey_KP_Begin: u32 = 65437;
+pub const kOfxKey_KP_Insert: u32 = 65438;
+pub const kOfxKey_KP_Delete: u32 = 65439;
+pub const kOfxKey_KP_Equal: u32 = 65469;
+pub const kOfxKey_KP_Multiply: u32 = 65450;
+pub const kOfxKey_KP_Add: u32 = 65451;
+pub const kOfxKey_KP_Separator: u32 = 65452;
+pub const kOfxKey_KP_Subtract: u32 = 65453;
+pub const kOfxKey_KP_Decimal: u32 = 65454;
+pub const kOfxKey_KP_Divide: u32 = 65455;
+pub const kOfxKey_KP_0: u32 = 65456;
+pub const kOfxKey_KP_1: u32 = 65457;
+pub const kOfxKey_KP_2: u32 = 65458;
+pub const kOfxKey_KP_3: u32 = 65459;
+pub const kOfxKey_KP_4: u32 = 65460;
+pub const kOfxKey_KP_5: u32 = 65461;
+pub const kOfxKey_KP_6: u32 = 65462;
+pub const kOfxKey_KP_7: u32 = 65463;
+pub const kOfxKey_KP_8: u32 = 65464;
+pub const kOfxKey_KP_9: u32 = 65465;
+pub const kOfxKey_F1: u32 = 65470;
+pub const kOfxKey_F2: u32 = 65471;
+pub const kOfxKey_F3: u32 = 65472;
+pub const kOfxKey_F4: u32 = 65473;
+pub const kOfxKey_F5: u32 = 65474;
+pub const kOfxKey_F6: u32 = 65475;
+pub const kOfxKey_F7: u32 = 65476;
+pub const kOfxKey_F8: u32 = 65477;
+pub const kOfxKey_F9: u32 = 65478;
+pub const kOfxKey_F10: u32 = 65479;
+pub const kOfxKey_F11: u32 = 65480;
+pub const kOfxKey_L1: u32 = 65480;
+pub const kOfxKey_F12: u32 = 65481;
+pub const kOfxKey_L2: u32 = 65481;
+pub const kOfxKey_F13: u32 = 65482;
+pub const kOfxKey_L3: u32 = 65482;
+pub const kOfxKey_F14: u32 = 65483;
+pub const kOfxKey_L4: u32 = 65483;
+pub const kOfxKey_F15: u32 = 65484;
+pub const kOfxKey_L5: u32 = 65484;
+pub const kOfxKey_F16: u32 = 65485;
+pub const kOfxKey_L6: u32 = 65485;
+pub const kOfxKey_F17: u32 = 65486;
+pub const kOfxKey_L7: u32 = 65486;
+pub const kOfxKey_F18: u32 = 65487;
+pub const kOfxKey_L8: u32 = 65487;
+pub const kOfxKey_F19: u32 = 65488;
+pub const kOfxKey_L9: u32 = 65488;
+pub const kOfxKey_F20: u32 = 65489;
+pub const kOfxKey_L10: u32 = 65489;
+pub const kOfxKey_F21: u32 = 65490;
+pub const kOfxKey_R1: u32 = 65490;
+pub const kOfxKey_F22: u32 = 65491;
+pub const kOfxKey_R2: u32 = 65491;
+pub const kOfxKey_F23: u32 = 65492;
+pub const kOfxKey_R3: u32 = 65492;
+pub const kOfxKey_F24: u32 = 65493;
+pub const kOfxKey_R4: u32 = 65493;
+pub const kOfxKey_F25: u32 = 65494;
+pub const kOfxKey_R5: u32 = 65494;
+pub const kOfxKey_F26: u32 = 65495;
+pub const kOfxKey_R6: u32 = 65495;
+pub const kOfxKey_F27: u32 = 65496;
+pub const kOfxKey_R7: u32 = 65496;
+pub const kOfxKey_F28: u32 = 65497;
+pub const kOfxKey_R8: u32 = 65497;
+pub const kOfxKey_F29: u32 = 65498;
+pub const kOfxKey_R9: u32 = 65498;
+pub const kOfxKey_F30: u32 = 65499;
+pub const kOfxKey_R10: u32 = 65499;
+pub const kOfxKey_F31: u32 = 65500;
+pub const kOfxKey_R11: u32 = 65500;
+pub const kOfxKey_F32: u32 = 65501;
+pub const kOfxKey_R12: u32 = 65501;
+pub const kOfxKey_F33: u32 = 65502;
+pub const kOfxKey_R13: u32 = 65502;
+pub const kOfxKey_F34: u32 = 65503;
+pub const kOfxKey_R14: u32 = 65503;
+pub const kOfxKey_F35: u32 = 65504;
+pub const kOfxKey_R15: u32 = 65504;
+pub const kOfxKey_Shift_L: u32 = 65505;
+pub const kOfxKey_Shift_R: u32 = 65506;
+pub const kOfxKey_Control_L: u32 = 65507;
+pub const kOfxKey_Control_R: u32 = 65508;
+pub const kOfxKey_Caps_Lock: u32 = 65509;
+pub const kOfxKey_Shift_Lock: u32 = 65510;
+pub const kOfxKey_Meta_L: u32 = 65511;
+pub const kOfxKey_Meta_R: u32 = 65512;
+pub const kOfxKey_Alt_L: u32 = 65513;
+pub const kOfxKey_Alt_R: u32 = 65514;
+pub const kOfxKey_Super_L: u32 = 65515;
+pub const kOfxKey_Super_R: u32 = 65516;
+pub const kOfxKey_Hyper_L: u32 = 65517;
+pub const kOfxKey_Hyper_R: u32 = 65518;
+pub const kOfxKey_space: u32 = 32;
+pub const kOfxKey_exclam: u32 = 33;
+pub const kOfxKey_quotedbl: u32 = 34;
+pub const kOfxKey_numbersign: u32 = 35;
+pub const kOfxKey_dollar: u32 = 36;
+pub const kOfxKey_percent: u32 = 37;
+pub const kOfxKey_ampersand: u32 = 38;
+pub const kOfxKey_apostrophe: u32 = 39;
+pub const kOfxKey_quoteright: u32 = 39;
+pub const kOfxKey_parenleft: u32 = 40;
+pub const kOfxKey_parenright: u32 = 41;
+pub const kOfxKey_asterisk: u32 = 42;
+pub const kOfxKey_plus: u32 = 43;
+pub const kOfxKey_comma: u32 = 44;
+pub const kOfxKey_minus: u32 = 45;
+pub const kOfxKey_period: u32 = 46;
+pub const kOfxKey_slash: u32 = 47;
+pub const kOfxKey_0: u32 = 48;
+pub const kOfxKey_1: u32 = 49;
+pub const kOfxKey_2: u32 = 50;
+pub const kOfxKey_3: u32 = 51;
+pub const kOfxKey_4: u32 = 52;
+pub const kOfxKey_5: u32 = 53;
+pub const kOfxKey_6: u32 = 54;
+pub const kOfxKey_7: u32 = 55;
+pub const kOfxKey_8: u32 = 56;
+pub const kOfxKey_9: u32 = 57;
+pub const kOfxKey_colon: u32 = 58;
+pub const kOfxKey_semicolon: u32 = 59;
+pub const kOfxKey_less: u32 = 60;
+pub const kOfxKey_equal: u32 = 61;
+pub const kOfxKey_greater: u32 = 62;
+pub const kOfxKey_question: u32 = 63;
+pub const kOfxKey_at: u32 = 64;
+pub const kOfxKey_A: u32 = 65;
+pub const kOfxKey_B: u32 = 66;
+pub const kOfxKey_C: u32 = 67;
+pub const kOfxKey_D: u32 = 68;
+pub const kOfxKey_E: u32 = 69;
+pub const kOfxKey_F: u32 = 70;
+pub const kOfxKey_G: u32 = 71;
+pub const kOfxKey_H: u32 = 72;
+pub const kOfxKey_I: u32 = 73;
+pub const kOfxKey_J: u32 = 74;
+pub const kOfxKey_K: u32 = 75;
+pub const kOfxKey_L: u32 = 76;
+pub const kOfxKey_M: u32 = 77;
+pub const kOfxKey_N: u32 = 78;
+pub const kOfxKey_O: u32 = 79;
+pub const kOfxKey_P: u32 = 80;
+pub const kOfxKey_Q: u32 = 81;
+pub const kOfxKey_R: u32 = 82;
+pub const kOfxKey_S: u32 = 83;
+pub const kOfxKey_T: u32 = 84;
+pub const kOfxKey_U: u32 = 85;
+pub const kOfxKey_V: u32 = 86;
+pub const kOfxKey_W: u32 = 87;
+pub const kOfxKey_X: u32 = 88;
+pub const kOfxKey_Y: u32 = 89;
+pub const kOfxKey_Z: u32 = 90;
+pub const kOfxKey_bracketleft: u32 = 91;
+pub const kOfxKey_backslash: u32 = 92;
+pub const kOfxKey_bracketright: u32 = 93;
+pub const kOfxKey_asciicircum: u32 = 94;
+pub const kOfxKey_underscore: u32 = 95;
+pub const kOfxKey_grave: u32 = 96;
+pub const kOfxKey_quoteleft: u32 = 96;
+pub const kOfxKey_a: u32 = 97;
+pub const kOfxKey_b: u32 = 98;
+pub const kOfxKey_c: u32 = 99;
+pub const kOfxKey_d: u32 = 100;
+pub const kOfxKey_e: u32 = 101;
+pub const kOfxKey_f: u32 = 102;
+pub const kOfxKey_g: u32 = 103;
+pub const kOfxKey_h: u32 = 104;
+pub const kOfxKey_i: u32 = 105;
+pub const kOfxKey_j: u32 = 106;
+pub const kOfxKey_k: u32 = 107;
+pub const kOfxKey_l: u32 = 108;
+pub const kOfxKey_m: u32 = 109;
+pub const kOfxKey_n: u32 = 110;
+pub const kOfxKey_o: u32 = 111;
+pub const kOfxKey_p: u32 = 112;
+pub const kOfxKey_q: u32 = 113;
+pub const kOfxKey_r: u32 = 114;
+pub const kOfxKey_s: u32 = 115;
+pub const kOfxKey_t: u32 = 116;
+pub const kOfxKey_u: u32 = 117;
+pub const kOfxKey_v: u32 = 118;
+pub const kOfxKey_w: u32 = 119;
+pub const kOfxKey_x: u32 = 120;
+pub const kOfxKey_y: u32 = 121;
+pub const kOfxKey_z: u32 = 122;
+pub const kOfxKey_braceleft: u32 = 123;
+pub const kOfxKey_bar: u32 = 124;
+pub const kOfxKey_braceright: u32 = 125;
+pub const kOfxKey_asciitilde: u32 = 126;
+pub const kOfxKey_nobreakspace: u32 = 160;
+pub const kOfxKey_exclamdown: u32 = 161;
+pub const kOfxKey_cent: u32 = 162;
+pub const kOfxKey_sterling: u32 = 163;
+pub const kOfxKey_currency: u32 = 164;
+pub const kOfxKey_yen: u32 = 165;
+pub const kOfxKey_brokenbar: u32 = 166;
+pub const kOfxKey_section: u32 = 167;
+pub const kOfxKey_diaeresis: u32 = 168;
+pub const kOfxKey_copyright: u32 = 169;
+pub const kOfxKey_ordfeminine: u32 = 170;
+pub const kOfxKey_guillemotleft: u32 = 171;
+pub const kOfxKey_notsign: u32 = 172;
+pub const kOfxKey_hyphen: u32 = 173;
+pub const kOfxKey_registered: u32 = 174;
+pub const kOfxKey_macron: u32 = 175;
+pub const kOfxKey_degree: u32 = 176;
+pub const kOfxKey_plusminus: u32 = 177;
+pub const kOfxKey_twosuperior: u32 = 178;
+pub const kOfxKey_threesuperior: u32 = 179;
+pub const kOfxKey_acute: u32 = 180;
+pub const kOfxKey_mu: u32 = 181;
+pub const kOfxKey_paragraph: u32 = 182;
+pub const kOfxKey_periodcentered: u32 = 183;
+pub const kOfxKey_cedilla: u32 = 184;
+pub const kOfxKey_onesuperior: u32 = 185;
+pub const kOfxKey_masculine: u32 = 186;
+pub const kOfxKey_guillemotright: u32 = 187;
+pub const kOfxKey_onequarter: u32 = 188;
+pub const kOfxKey_onehalf: u32 = 189;
+pub const kOfxKey_threequarters: u32 = 190;
+pub const kOfxKey_questiondown: u32 = 191;
+pub const kOfxKey_Agrave: u32 = 192;
+pub const kOfxKey_Aacute: u32 = 193;
+pub const kOfxKey_Acircumflex: u32 = 194;
+pub const kOfxKey_Atilde: u32 = 195;
+pub const kOfxKey_Adiaeresis: u32 = 196;
+pub const kOfxKey_Aring: u32 = 197;
+pub const kOfxKey_AE: u32 = 198;
+pub const kOfxKey_Ccedilla: u32 = 199;
+pub const kOfxKey_Egrave: u32 = 200;
+pub const kOfxKey_Eacute: u32 = 201;
+pub const kOfxKey_Ecircumflex: u32 = 202;
+pub const kOfxKey_Ediaeresis: u32 = 203;
+pub const kOfxKey_Igrave: u32 = 204;
+pub const kOfxKey_Iacute: u32 = 205;
+pub const kOfxKey_Icircumflex: u32 = 206;
+pub const kOfxKey_Idiaeresis: u32 = 207;
+pub const kOfxKey_ETH: u32 = 208;
+pub const kOfxKey_Eth: u32 = 208;
+pub const kOfxKey_Ntilde: u32 = 209;
+pub const kOfxKey_Ograve: u32 = 210;
+pub const kOfxKey_Oacute: u32 = 211;
+pub const kOfxKey_Ocircumflex: u32 = 212;
+pub const kOfxKey_Otilde: u32 = 213;
+pub const kOfxKey_Odiaeresis: u32 = 214;
+pub const kOfxKey_multiply: u32 = 215;
+pub const kOfxKey_Ooblique: u32 = 216;
+pub const kOfxKey_Ugrave: u32 = 217;
+pub const kOfxKey_Uacute: u32 = 218;
+pub const kOfxKey_Ucircumflex: u32 = 219;
+pub const kOfxKey_Udiaeresis: u32 = 220;
+pub const kOfxKey_Yacute: u32 = 221;
+pub const kOfxKey_THORN: u32 = 222;
+pub const kOfxKey_ssharp: u32 = 223;
+pub const kOfxKey_agrave: u32 = 224;
+pub const kOfxKey_aacute: u32 = 225;
+pub const kOfxKey_acircumflex: u32 = 226;
+pub const kOfxKey_atilde: u32 = 227;
+pub const kOfxKey_adiaeresis: u32 = 228;
+pub const kOfxKey_aring: u32 = 229;
+pub const kOfxKey_ae: u32 = 230;
+pub const kOfxKey_ccedilla: u32 = 231;
+pub const kOfxKey_egrave: u32 = 232;
+pub const kOfxKey_eacute: u32 = 233;
+pub const kOfxKey_ecircumflex: u32 = 234;
+pub const kOfxKey_ediaeresis: u32 = 235;
+pub const kOfxKey_igrave: u32 = 236;
+pub const kOfxKey_iacute: u32 = 237;
+pub const kOfxKey_icircumflex: u32 = 238;
+pub const kOfxKey_idiaeresis: u32 = 239;
+pub const kOfxKey_eth: u32 = 240;
+pub const kOfxKey_ntilde: u32 = 241;
+pub const kOfxKey_ograve: u32 = 242;
+pub const kOfxKey_oacute: u32 = 243;
+pub const kOfxKey_ocircumflex: u32 = 244;
+pub const kOfxKey_otilde: u32 = 245;
+pub const kOfxKey_odiaeresis: u32 = 246;
+pub const kOfxKey_division: u32 = 247;
+pub const kOfxKey_oslash: u32 = 248;
+pub const kOfxKey_ugrave: u32 = 249;
+pub const kOfxKey_uacute: u32 = 250;
+pub const kOfxKey_ucircumflex: u32 = 251;
+pub const kOfxKey_udiaeresis: u32 = 252;
+pub const kOfxKey_yacute: u32 = 253;
+pub const kOfxKey_thorn: u32 = 254;
+pub const kOfxKey_ydiaeresis: u32 = 255;
+pub const kOfxTimeLineSuite: &'static [u8; 17usize] = b"OfxTimeLineSuite\0";
+pub const kFnOfxImageComponentMotionVectors: &'static [u8; 48usize] =
+    b"uk.co.thefoundry.OfxImageComponentMotionVectors\0";
+pub const kFnOfxImageComponentStereoDisparity: &'static [u8; 50usize] =
+    b"uk.co.thefoundry.OfxImageComponentStereoDisparity\0";
+pub const kFnOfxImagePlaneForwardMotionVector: &'static [u8; 50usize] =
+    b"uk.co.thefoundry.OfxImagePlaneForwardMotionVector\0";
+pub const kFnOfxImagePlaneBackwardMotionVector: &'static [u8; 47usize] =
+    b"uk.co.thefoundry.OfxImagePlaneBackMotionVector\0";
+pub const kFnOfxImagePlaneColour: &'static [u8; 37usize] =
+    b"uk.co.thefoundry.OfxImagePlaneColour\0";
+pub const kFnOfxImagePlaneStereoDisparityLeft: &'static [u8; 50usize] =
+    b"uk.co.thefoundry.OfxImagePlaneStereoDisparityLeft\0";
+pub const kFnOfxImagePlaneStereoDisparityRight: &'static [u8; 51usize] =
+    b"uk.co.thefoundry.OfxImagePlaneStereoDisparityRight\0";
+pub const kFnOfxImageEffectPropMultiPlanar: &'static [u8; 47usize] =
+    b"uk.co.thefoundry.OfxImageEffectPropMultiPlanar\0";
+pub const kFnOfxImageEffectPropPassThroughComponents: &'static [u8; 57usize] =
+    b"uk.co.thefoundry.OfxImageEffectPropPassThroughComponents\0";
+pub const kFnOfxImageEffectPropComponentsPresent: &'static [u8; 53usize] =
+    b"uk.co.thefoundry.OfxImageEffectClipPropPlanesPresent\0";
+pub const kOfxImageEffectPropRenderAllPlanes: &'static [u8; 34usize] =
+    b"OfxImageEffectPropRenderAllPlanes\0";
+pub const kOfxImageEffectPropRenderPlanes: &'static [u8; 31usize] =
+    b"OfxImageEffectPropRenderPlanes\0";
+pub const kOfxImageEffectPropIdentityPlane: &'static [u8; 32usize] =
+    b"OfxImageEffectPropIdentityPlane\0";
+pub const kFnOfxImageEffectActionGetClipComponents: &'static [u8; 55usize] =
+    b"uk.co.thefoundry.OfxImageEffectActionGetClipComponents\0";
+pub const kFnOfxImageEffectPropPassThroughClip: &'static [u8; 48usize] =
+    b"uk.co.thefoundry.ImageEffectPropPassThroughClip\0";
+pub const kFnOfxImageEffectPropPassThroughTime: &'static [u8; 48usize] =
+    b"uk.co.thefoundry.ImageEffectPropPassThroughTime\0";
+pub const kFnOfxImageEffectPropPassThroughView: &'static [u8; 48usize] =
+    b"uk.co.thefoundry.ImageEffectPropPassThroughView\0";
+pub const kFnOfxImageEffectActionGetClipComponentsPropString: &'static [u8; 32usize] =
+    b"uk.co.thefoundry.OfxNeededComp_\0";
+pub const kFnOfxImageEffectPropViewAware: &'static [u8; 45usize] =
+    b"uk.co.thefoundry.OfxImageEffectPropViewAware\0";
+pub const kFnOfxImageEffectPropViewInvariance: &'static [u8; 50usize] =
+    b"uk.co.thefoundry.OfxImageEffectPropViewInvariance\0";
+pub const kFnOfxImageEffectActionGetFrameViewsNeeded: &'static [u8; 57usize] =
+    b"uk.co.thefoundry.OfxImageEffectActionGetFrameViewsNeeded\0";
+pub const kFnOfxImageEffectPropView: &'static [u8; 40usize] =
+    b"uk.co.thefoundry.OfxImageEffectPropView\0";
+pub const kFnOfxParamTypeViewChooser: &'static [u8; 41usize] =
+    b"uk.co.thefoundry.OfxParamTypeViewChooser\0";
+pub const kFnOfxParamTypeViewPair: &'static [u8; 38usize] =
+    b"uk.co.thefoundry.OfxParamTypeViewPair\0";
+pub const kFnOfxImageEffectPlaneSuite: &'static [u8; 44usize] =
+    b"uk.co.thefoundry.FnOfxImageEffectPlaneSuite\0";
+pub const kFnOfxImageEffectCanTransform: &'static [u8; 29usize] = b"FnOfxImageEffectCanTransform\0";
+pub const kFnOfxImageEffectActionGetTransform: &'static [u8; 52usize] =
+    b"uk.co.thefoundry.FnOfxImageEffectActionGetTransform\0";
+pub const kFnOfxPropMatrix2D: &'static [u8; 18usize] = b"FnOfxPropMatrix2D\0";
+pub type size_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxPropertySetStruct {
+    _unused: [u8; 0],
+}
+pub type OfxPropertySetHandle = *mut OfxPropertySetStruct;
+pub type OfxStatus = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxHost {
+    pub host: OfxPropertySetHandle,
+    pub fetchSuite: ::std::option::Option<
+        unsafe extern "C" fn(
+            host: OfxPropertySetHandle,
+            suiteName: *const ::std::os::raw::c_char,
+            suiteVersion: ::std::os::raw::c_int,
+        ) -> *const ::std::os::raw::c_void,
+    >,
+}
+pub type OfxPluginEntryPoint = ::std::option::Option<
+    unsafe extern "C" fn(
+        action: *const ::std::os::raw::c_char,
+        handle: *const ::std::os::raw::c_void,
+        inArgs: OfxPropertySetHandle,
+        outArgs: OfxPropertySetHandle,
+    ) -> OfxStatus,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxPlugin {
+    pub pluginApi: *const ::std::os::raw::c_char,
+    pub apiVersion: ::std::os::raw::c_int,
+    pub pluginIdentifier: *const ::std::os::raw::c_char,
+    pub pluginVersionMajor: ::std::os::raw::c_uint,
+    pub pluginVersionMinor: ::std::os::raw::c_uint,
+    pub setHost: ::std::option::Option<unsafe extern "C" fn(host: *mut OfxHost)>,
+    pub mainEntry: OfxPluginEntryPoint,
+}
+extern "C" {
+    pub fn OfxGetPlugin(nth: ::std::os::raw::c_int) -> *mut OfxPlugin;
+}
+extern "C" {
+    pub fn OfxGetNumberOfPlugins() -> ::std::os::raw::c_int;
+}
+pub type OfxTime = f64;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRangeI {
+    pub min: ::std::os::raw::c_int,
+    pub max: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRangeD {
+    pub min: f64,
+    pub max: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxPointI {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxPointD {
+    pub x: f64,
+    pub y: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRectI {
+    pub x1: ::std::os::raw::c_int,
+    pub y1: ::std::os::raw::c_int,
+    pub x2: ::std::os::raw::c_int,
+    pub y2: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRectD {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxPropertySuiteV1 {
+    pub propSetPointer: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub propSetString: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub propSetDouble: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: f64,
+        ) -> OfxStatus,
+    >,
+    pub propSetInt: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub propSetPointerN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *const *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub propSetStringN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *const *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub propSetDoubleN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *const f64,
+        ) -> OfxStatus,
+    >,
+    pub propSetIntN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *const ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub propGetPointer: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *mut *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub propGetString: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *mut *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub propGetDouble: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *mut f64,
+        ) -> OfxStatus,
+    >,
+    pub propGetInt: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            index: ::std::os::raw::c_int,
+            value: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub propGetPointerN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *mut *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub propGetStringN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *mut *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub propGetDoubleN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *mut f64,
+        ) -> OfxStatus,
+    >,
+    pub propGetIntN: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: ::std::os::raw::c_int,
+            value: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub propReset: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub propGetDimension: ::std::option::Option<
+        unsafe extern "C" fn(
+            properties: OfxPropertySetHandle,
+            property: *const ::std::os::raw::c_char,
+            count: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxParamStruct {
+    _unused: [u8; 0],
+}
+pub type OfxParamHandle = *mut OfxParamStruct;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxParamSetStruct {
+    _unused: [u8; 0],
+}
+pub type OfxParamSetHandle = *mut OfxParamSetStruct;
+pub type OfxCustomParamInterpFuncV1 = ::std::option::Option<
+    unsafe extern "C" fn(
+        instance: OfxParamSetHandle,
+        inArgs: OfxPropertySetHandle,
+        outArgs: OfxPropertySetHandle,
+    ) -> OfxStatus,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxParameterSuiteV1 {
+    pub paramDefine: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramSet: OfxParamSetHandle,
+            paramType: *const ::std::os::raw::c_char,
+            name: *const ::std::os::raw::c_char,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub paramGetHandle: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramSet: OfxParamSetHandle,
+            name: *const ::std::os::raw::c_char,
+            param: *mut OfxParamHandle,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub paramSetGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramSet: OfxParamSetHandle,
+            propHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub paramGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            propHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub paramGetValue:
+        ::std::option::Option<unsafe extern "C" fn(paramHandle: OfxParamHandle, ...) -> OfxStatus>,
+    pub paramGetValueAtTime: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime, ...) -> OfxStatus,
+    >,
+    pub paramGetDerivative: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime, ...) -> OfxStatus,
+    >,
+    pub paramGetIntegral: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            time1: OfxTime,
+            time2: OfxTime,
+            ...
+        ) -> OfxStatus,
+    >,
+    pub paramSetValue:
+        ::std::option::Option<unsafe extern "C" fn(paramHandle: OfxParamHandle, ...) -> OfxStatus>,
+    pub paramSetValueAtTime: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime, ...) -> OfxStatus,
+    >,
+    pub paramGetNumKeys: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            numberOfKeys: *mut ::std::os::raw::c_uint,
+        ) -> OfxStatus,
+    >,
+    pub paramGetKeyTime: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            nthKey: ::std::os::raw::c_uint,
+            time: *mut OfxTime,
+        ) -> OfxStatus,
+    >,
+    pub paramGetKeyIndex: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            time: OfxTime,
+            direction: ::std::os::raw::c_int,
+            index: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub paramDeleteKey: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime) -> OfxStatus,
+    >,
+    pub paramDeleteAllKeys:
+        ::std::option::Option<unsafe extern "C" fn(paramHandle: OfxParamHandle) -> OfxStatus>,
+    pub paramCopy: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramTo: OfxParamHandle,
+            paramFrom: OfxParamHandle,
+            dstOffset: OfxTime,
+            frameRange: *const OfxRangeD,
+        ) -> OfxStatus,
+    >,
+    pub paramEditBegin: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramSet: OfxParamSetHandle,
+            name: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub paramEditEnd:
+        ::std::option::Option<unsafe extern "C" fn(paramSet: OfxParamSetHandle) -> OfxStatus>,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxInteract {
+    _unused: [u8; 0],
+}
+pub type OfxInteractHandle = *mut OfxInteract;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxInteractSuiteV1 {
+    pub interactSwapBuffers: ::std::option::Option<
+        unsafe extern "C" fn(interactInstance: OfxInteractHandle) -> OfxStatus,
+    >,
+    pub interactRedraw: ::std::option::Option<
+        unsafe extern "C" fn(interactInstance: OfxInteractHandle) -> OfxStatus,
+    >,
+    pub interactGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            interactInstance: OfxInteractHandle,
+            property: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxMessageSuiteV1 {
+    pub message: ::std::option::Option<
+        unsafe extern "C" fn(
+            handle: *mut ::std::os::raw::c_void,
+            messageType: *const ::std::os::raw::c_char,
+            messageId: *const ::std::os::raw::c_char,
+            format: *const ::std::os::raw::c_char,
+            ...
+        ) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxMessageSuiteV2 {
+    pub message: ::std::option::Option<
+        unsafe extern "C" fn(
+            handle: *mut ::std::os::raw::c_void,
+            messageType: *const ::std::os::raw::c_char,
+            messageId: *const ::std::os::raw::c_char,
+            format: *const ::std::os::raw::c_char,
+            ...
+        ) -> OfxStatus,
+    >,
+    pub setPersistentMessage: ::std::option::Option<
+        unsafe extern "C" fn(
+            handle: *mut ::std::os::raw::c_void,
+            messageType: *const ::std::os::raw::c_char,
+            messageId: *const ::std::os::raw::c_char,
+            format: *const ::std::os::raw::c_char,
+            ...
+        ) -> OfxStatus,
+    >,
+    pub clearPersistentMessage: ::std::option::Option<
+        unsafe extern "C" fn(handle: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxMemorySuiteV1 {
+    pub memoryAlloc: ::std::option::Option<
+        unsafe extern "C" fn(
+            handle: *mut ::std::os::raw::c_void,
+            nBytes: size_t,
+            allocatedData: *mut *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub memoryFree: ::std::option::Option<
+        unsafe extern "C" fn(allocatedData: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxMutex {
+    _unused: [u8; 0],
+}
+pub type OfxMutexHandle = *mut OfxMutex;
+pub type OfxThreadFunctionV1 = ::std::option::Option<
+    unsafe extern "C" fn(
+        threadIndex: ::std::os::raw::c_uint,
+        threadMax: ::std::os::raw::c_uint,
+        customArg: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxMultiThreadSuiteV1 {
+    pub multiThread: ::std::option::Option<
+        unsafe extern "C" fn(
+            func: OfxThreadFunctionV1,
+            nThreads: ::std::os::raw::c_uint,
+            customArg: *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub multiThreadNumCPUs: ::std::option::Option<
+        unsafe extern "C" fn(nCPUs: *mut ::std::os::raw::c_uint) -> OfxStatus,
+    >,
+    pub multiThreadIndex: ::std::option::Option<
+        unsafe extern "C" fn(threadIndex: *mut ::std::os::raw::c_uint) -> OfxStatus,
+    >,
+    pub multiThreadIsSpawnedThread:
+        ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_int>,
+    pub mutexCreate: ::std::option::Option<
+        unsafe extern "C" fn(
+            mutex: *mut OfxMutexHandle,
+            lockCount: ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub mutexDestroy:
+        ::std::option::Option<unsafe extern "C" fn(mutex: OfxMutexHandle) -> OfxStatus>,
+    pub mutexLock: ::std::option::Option<unsafe extern "C" fn(mutex: OfxMutexHandle) -> OfxStatus>,
+    pub mutexUnLock:
+        ::std::option::Option<unsafe extern "C" fn(mutex: OfxMutexHandle) -> OfxStatus>,
+    pub mutexTryLock:
+        ::std::option::Option<unsafe extern "C" fn(mutex: OfxMutexHandle) -> OfxStatus>,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxImageEffectStruct {
+    _unused: [u8; 0],
+}
+pub type OfxImageEffectHandle = *mut OfxImageEffectStruct;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxImageClipStruct {
+    _unused: [u8; 0],
+}
+pub type OfxImageClipHandle = *mut OfxImageClipStruct;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxImageMemoryStruct {
+    _unused: [u8; 0],
+}
+pub type OfxImageMemoryHandle = *mut OfxImageMemoryStruct;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxImageEffectSuiteV1 {
+    pub getPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            imageEffect: OfxImageEffectHandle,
+            propHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub getParamSet: ::std::option::Option<
+        unsafe extern "C" fn(
+            imageEffect: OfxImageEffectHandle,
+            paramSet: *mut OfxParamSetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipDefine: ::std::option::Option<
+        unsafe extern "C" fn(
+            imageEffect: OfxImageEffectHandle,
+            name: *const ::std::os::raw::c_char,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipGetHandle: ::std::option::Option<
+        unsafe extern "C" fn(
+            imageEffect: OfxImageEffectHandle,
+            name: *const ::std::os::raw::c_char,
+            clip: *mut OfxImageClipHandle,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            propHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipGetImage: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            region: *const OfxRectD,
+            imageHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipReleaseImage:
+        ::std::option::Option<unsafe extern "C" fn(imageHandle: OfxPropertySetHandle) -> OfxStatus>,
+    pub clipGetRegionOfDefinition: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            bounds: *mut OfxRectD,
+        ) -> OfxStatus,
+    >,
+    pub abort: ::std::option::Option<
+        unsafe extern "C" fn(imageEffect: OfxImageEffectHandle) -> ::std::os::raw::c_int,
+    >,
+    pub imageMemoryAlloc: ::std::option::Option<
+        unsafe extern "C" fn(
+            instanceHandle: OfxImageEffectHandle,
+            nBytes: size_t,
+            memoryHandle: *mut OfxImageMemoryHandle,
+        ) -> OfxStatus,
+    >,
+    pub imageMemoryFree: ::std::option::Option<
+        unsafe extern "C" fn(memoryHandle: OfxImageMemoryHandle) -> OfxStatus,
+    >,
+    pub imageMemoryLock: ::std::option::Option<
+        unsafe extern "C" fn(
+            memoryHandle: OfxImageMemoryHandle,
+            returnedPtr: *mut *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub imageMemoryUnlock: ::std::option::Option<
+        unsafe extern "C" fn(memoryHandle: OfxImageMemoryHandle) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxYUVAColourB {
+    pub y: ::std::os::raw::c_uchar,
+    pub u: ::std::os::raw::c_uchar,
+    pub v: ::std::os::raw::c_uchar,
+    pub a: ::std::os::raw::c_uchar,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxYUVAColourS {
+    pub y: ::std::os::raw::c_ushort,
+    pub u: ::std::os::raw::c_ushort,
+    pub v: ::std::os::raw::c_ushort,
+    pub a: ::std::os::raw::c_ushort,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxYUVAColourF {
+    pub y: f32,
+    pub u: f32,
+    pub v: f32,
+    pub a: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBAColourB {
+    pub r: ::std::os::raw::c_uchar,
+    pub g: ::std::os::raw::c_uchar,
+    pub b: ::std::os::raw::c_uchar,
+    pub a: ::std::os::raw::c_uchar,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBAColourS {
+    pub r: ::std::os::raw::c_ushort,
+    pub g: ::std::os::raw::c_ushort,
+    pub b: ::std::os::raw::c_ushort,
+    pub a: ::std::os::raw::c_ushort,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBAColourF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBAColourD {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBColourB {
+    pub r: ::std::os::raw::c_uchar,
+    pub g: ::std::os::raw::c_uchar,
+    pub b: ::std::os::raw::c_uchar,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBColourS {
+    pub r: ::std::os::raw::c_ushort,
+    pub g: ::std::os::raw::c_ushort,
+    pub b: ::std::os::raw::c_ushort,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBColourF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxRGBColourD {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxProgressSuiteV1 {
+    pub progressStart: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            label: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub progressUpdate: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            progress: f64,
+        ) -> OfxStatus,
+    >,
+    pub progressEnd: ::std::option::Option<
+        unsafe extern "C" fn(effectInstance: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxProgressSuiteV2 {
+    pub progressStart: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            message: *const ::std::os::raw::c_char,
+            messageid: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub progressUpdate: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            progress: f64,
+        ) -> OfxStatus,
+    >,
+    pub progressEnd: ::std::option::Option<
+        unsafe extern "C" fn(effectInstance: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxTimeLineSuiteV1 {
+    pub getTime: ::std::option::Option<
+        unsafe extern "C" fn(instance: *mut ::std::os::raw::c_void, time: *mut f64) -> OfxStatus,
+    >,
+    pub gotoTime: ::std::option::Option<
+        unsafe extern "C" fn(instance: *mut ::std::os::raw::c_void, time: f64) -> OfxStatus,
+    >,
+    pub getTimeBounds: ::std::option::Option<
+        unsafe extern "C" fn(
+            instance: *mut ::std::os::raw::c_void,
+            firstTime: *mut f64,
+            lastTime: *mut f64,
+        ) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FnOfxImageEffectPlaneSuiteV1 {
+    pub clipGetImagePlane: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            plane: *const ::std::os::raw::c_char,
+            region: *const OfxRectD,
+            imageHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FnOfxImageEffectPlaneSuiteV2 {
+    pub clipGetImagePlane: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            view: ::std::os::raw::c_int,
+            plane: *const ::std::os::raw::c_char,
+            region: *const OfxRectD,
+            imageHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipGetRegionOfDefinition: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            view: ::std::os::raw::c_int,
+            bounds: *mut OfxRectD,
+        ) -> OfxStatus,
+    >,
+    pub getViewName: ::std::option::Option<
+        unsafe extern "C" fn(
+            effect: OfxImageEffectHandle,
+            view: ::std::os::raw::c_int,
+            viewName: *mut *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub getViewCount: ::std::option::Option<
+        unsafe extern "C" fn(
+            effect: OfxImageEffectHandle,
+            nViews: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+}
+pub const eOfxStatus_Unused: eOfxStatus = -1;
+pub const eOfxStatus_OK: eOfxStatus = 0;
+pub const eOfxStatus_ReplyDefault: eOfxStatus = 14;
+pub const eOfxStatus_Failed: eOfxStatus = 1;
+pub const eOfxStatus_ErrFatal: eOfxStatus = 2;
+pub const eOfxStatus_ErrBadHandle: eOfxStatus = 9;
+pub const eOfxStatus_ErrBadIndex: eOfxStatus = 10;
+pub const eOfxStatus_ErrValue: eOfxStatus = 11;
+pub const eOfxStatus_ErrUnknown: eOfxStatus = 3;
+pub const eOfxStatus_ErrMemory: eOfxStatus = 8;
+pub const eOfxStatus_ErrUnsupported: eOfxStatus = 5;
+pub const eOfxStatus_ErrMissingHostFeature: eOfxStatus = 4;
+pub type eOfxStatus = i32;
@@ -0,0 +1,60 @@
+// Pre-generated bindings for the Nuke camera extension, split by hand from the
+// bindgen output as described in bindings.rs, included with the `camera` feature
+
+pub const kNukeOfxCameraSuite: &'static [u8; 19usize] = b"NukeOfxCameraSuite\0";
+pub const kNukeOfxCameraProjectionModePerspective: f64 = 0.0;
+pub const kNukeOfxCameraProjectionModeOrthographic: f64 = 1.0;
+pub const kNukeOfxCameraProjectionModeUV: f64 = 2.0;
+pub const kNukeOfxCameraProjectionModeSpherical: f64 = 3.0;
+pub const kNukeOfxCameraParamProjectionMode: &'static [u8; 16usize] = b"projection_mode\0";
+pub const kNukeOfxCameraParamFocalLength: &'static [u8; 6usize] = b"focal\0";
+pub const kNukeOfxCameraParamHorizontalAperture: &'static [u8; 10usize] = b"haperture\0";
+pub const kNukeOfxCameraParamVerticalAperture: &'static [u8; 10usize] = b"vaperture\0";
+pub const kNukeOfxCameraParamNear: &'static [u8; 5usize] = b"near\0";
+pub const kNukeOfxCameraParamFar: &'static [u8; 4usize] = b"far\0";
+pub const kNukeOfxCameraParamWindowTranslate: &'static [u8; 14usize] = b"win_translate\0";
+pub const kNukeOfxCameraParamWindowScale: &'static [u8; 10usize] = b"win_scale\0";
+pub const kNukeOfxCameraParamWindowRoll: &'static [u8; 8usize] = b"winroll\0";
+pub const kNukeOfxCameraParamFocalPoint: &'static [u8; 12usize] = b"focal_point\0";
+pub const kNukeOfxCameraParamPositionMatrix: &'static [u8; 16usize] = b"position_matrix\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NukeOfxCameraStruct {
+    _unused: [u8; 0],
+}
+pub type NukeOfxCameraHandle = *mut NukeOfxCameraStruct;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NukeOfxCameraSuiteV1 {
+    pub cameraDefine: ::std::option::Option<
+        unsafe extern "C" fn(
+            pluginHandle: OfxImageEffectHandle,
+            name: *const ::std::os::raw::c_char,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub cameraGetHandle: ::std::option::Option<
+        unsafe extern "C" fn(
+            pluginHandle: OfxImageEffectHandle,
+            name: *const ::std::os::raw::c_char,
+            camera: *mut NukeOfxCameraHandle,
+            propertySet: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub cameraGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            camera: NukeOfxCameraHandle,
+            propHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub cameraGetParameter: ::std::option::Option<
+        unsafe extern "C" fn(
+            camera: NukeOfxCameraHandle,
+            paramName: *const ::std::os::raw::c_char,
+            time: f64,
+            view: ::std::os::raw::c_int,
+            baseReturnAddress: *mut f64,
+            returnSize: ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+}
@@ -0,0 +1,30 @@
+// Pre-generated bindings for the dialog suite, split by hand from the
+// bindgen output as described in bindings.rs, included with the `dialog` feature
+
+pub const kOfxDialogSuite: &'static [u8; 15usize] = b"OfxDialogSuite\0";
+pub const kOfxActionDialog: &'static [u8; 16usize] = b"OfxActionDialog\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxDialogSuiteV1 {
+    pub requestDialog: ::std::option::Option<
+        unsafe extern "C" fn(instanceData: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+    pub notifyRedrawPending: ::std::option::Option<unsafe extern "C" fn() -> OfxStatus>,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxDialogSuiteV2 {
+    pub requestDialog: ::std::option::Option<
+        unsafe extern "C" fn(
+            instance: OfxImageEffectHandle,
+            inArgs: OfxPropertySetHandle,
+            instanceData: *mut ::std::os::raw::c_void,
+        ) -> OfxStatus,
+    >,
+    pub notifyRedrawPending: ::std::option::Option<
+        unsafe extern "C" fn(
+            instance: OfxImageEffectHandle,
+            inArgs: OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+}
@@ -0,0 +1,176 @@
+// Pre-generated bindings for the Natron extensions, split by hand from the
+// bindgen output as described in bindings.rs, included with the `natron` feature
+
+pub const kNatronOfxHostName: &'static [u8; 16usize] = b"fr.inria.Natron\0";
+pub const kNatronOfxHostIsNatron: &'static [u8; 22usize] = b"NatronOfxHostIsNatron\0";
+pub const kNatronOfxParamHostPropSupportsDynamicChoices: &'static [u8; 45usize] =
+    b"NatronOfxParamHostPropSupportsDynamicChoices\0";
+pub const kNatronOfxParamStringSublabelName: &'static [u8; 33usize] =
+    b"NatronOfxParamStringSublabelName\0";
+pub const kNatronOfxImageComponentsPlaneName: &'static [u8; 35usize] =
+    b"NatronOfxImageComponentsPlaneName_\0";
+pub const kNatronOfxImageComponentsPlaneLabel: &'static [u8; 13usize] = b"_PlaneLabel_\0";
+pub const kNatronOfxImageComponentsPlaneChannelsLabel: &'static [u8; 16usize] =
+    b"_ChannelsLabel_\0";
+pub const kNatronOfxImageComponentsPlaneChannel: &'static [u8; 10usize] = b"_Channel_\0";
+pub const kNatronOfxImageComponentXY: &'static [u8; 26usize] = b"NatronOfxImageComponentXY\0";
+pub const kNatronOfxParamPropChoiceCascading: &'static [u8; 34usize] =
+    b"NatronOfxParamPropChoiceCascading\0";
+pub const kNatronOfxParamPropChoiceHostCanAddOptions: &'static [u8; 42usize] =
+    b"NatronOfxParamPropChoiceHostCanAddOptions\0";
+pub const kNatronOfxParamOutputChannels: &'static [u8; 15usize] = b"outputChannels\0";
+pub const kNatronOfxExtraCreatedPlanes: &'static [u8; 28usize] = b"NatronOfxExtraCreatedPlanes\0";
+pub const kNatronOfxImageEffectPropChannelSelector: &'static [u8; 40usize] =
+    b"NatronOfxImageEffectPropChannelSelector\0";
+pub const kNatronOfxImageEffectPropHostMasking: &'static [u8; 37usize] =
+    b"kNatronOfxImageEffectPropHostMasking\0";
+pub const kNatronOfxImageEffectPropHostMixing: &'static [u8; 36usize] =
+    b"kNatronOfxImageEffectPropHostMixing\0";
+pub const kNatronOfxParamProcessR: &'static [u8; 23usize] = b"NatronOfxParamProcessR\0";
+pub const kNatronOfxParamProcessRLabel: &'static [u8; 2usize] = b"R\0";
+pub const kNatronOfxParamProcessRHint: &'static [u8; 23usize] = b"Process red component.\0";
+pub const kNatronOfxParamProcessG: &'static [u8; 23usize] = b"NatronOfxParamProcessG\0";
+pub const kNatronOfxParamProcessGLabel: &'static [u8; 2usize] = b"G\0";
+pub const kNatronOfxParamProcessGHint: &'static [u8; 25usize] = b"Process green component.\0";
+pub const kNatronOfxParamProcessB: &'static [u8; 23usize] = b"NatronOfxParamProcessB\0";
+pub const kNatronOfxParamProcessBLabel: &'static [u8; 2usize] = b"B\0";
+pub const kNatronOfxParamProcessBHint: &'static [u8; 24usize] = b"Process blue component.\0";
+pub const kNatronOfxParamProcessA: &'static [u8; 23usize] = b"NatronOfxParamProcessA\0";
+pub const kNatronOfxParamProcessALabel: &'static [u8; 2usize] = b"A\0";
+pub const kNatronOfxParamProcessAHint: &'static [u8; 25usize] = b"Process alpha component.\0";
+pub const kNatronOfxImageEffectContextTracker: &'static [u8; 35usize] =
+    b"NatronOfxImageEffectContextTracker\0";
+pub const kNatronParamTrackingPrevious: &'static [u8; 14usize] = b"trackPrevious\0";
+pub const kNatronParamTrackingNext: &'static [u8; 10usize] = b"trackNext\0";
+pub const kNatronParamTrackingBackward: &'static [u8; 14usize] = b"trackBackward\0";
+pub const kNatronParamTrackingForward: &'static [u8; 13usize] = b"trackForward\0";
+pub const kNatronOfxParamPropDoubleTypeMatrix3x3: &'static [u8; 28usize] =
+    b"NatronOfxParamTypeMatrix3x3\0";
+pub const kNatronOfxParamPropTypeRectangle: &'static [u8; 32usize] =
+    b"NatronOfxParamPropTypeRectangle\0";
+pub const kNatronOfxParamPropIsInstanceSpecific: &'static [u8; 37usize] =
+    b"NatronOfxParamPropIsInstanceSpecific\0";
+pub const kNatronOfxImageEffectPropDeprecated: &'static [u8; 35usize] =
+    b"NatronOfxImageEffectPropDeprecated\0";
+pub const kNatronParamFormatChoice: &'static [u8; 24usize] = b"NatronParamFormatChoice\0";
+pub const kNatronParamFormatSize: &'static [u8; 22usize] = b"NatronParamFormatSize\0";
+pub const kNatronParamFormatPar: &'static [u8; 21usize] = b"NatronParamFormatPar\0";
+pub const kNatronOfxImageEffectPropProjectId: &'static [u8; 34usize] =
+    b"NatronOfxImageEffectPropProjectId\0";
+pub const kNatronOfxImageEffectPropGroupId: &'static [u8; 32usize] =
+    b"NatronOfxImageEffectPropGroupId\0";
+pub const kNatronOfxImageEffectPropInstanceId: &'static [u8; 35usize] =
+    b"NatronOfxImageEffectPropInstanceId\0";
+pub const kNatronOfxImageEffectPluginUsesMultipleThread: &'static [u8; 45usize] =
+    b"NatronOfxImageEffectPluginUsesMultipleThread\0";
+pub const kNatronOfxPropNativeOverlays: &'static [u8; 28usize] = b"NatronOfxPropNativeOverlays\0";
+pub const kNatronNativeOverlayType: &'static [u8; 24usize] = b"NatronNativeOverlayType\0";
+pub const kNatronNativeOverlayParameterHint: &'static [u8; 33usize] =
+    b"NatronNativeOverlayParameterHint\0";
+pub const kNatronNativeOverlayParameterType: &'static [u8; 33usize] =
+    b"NatronNativeOverlayParameterType\0";
+pub const kNatronNativeOverlayParameterName: &'static [u8; 33usize] =
+    b"NatronNativeOverlayParameterName\0";
+pub const kNatronOfxPropDescriptionIsMarkdown: &'static [u8; 35usize] =
+    b"NatronOfxPropDescriptionIsMarkdown\0";
+pub const kNatronOfxGroupParamPropIsDialog: &'static [u8; 32usize] =
+    b"NatronOfxGroupParamPropIsDialog\0";
+pub const kNatronOfxParamUndoRedoText: &'static [u8; 27usize] = b"NatronOfxParamUndoRedoText\0";
+pub const kNatronOfxParamUndoRedoState: &'static [u8; 28usize] = b"NatronOfxParamUndoRedoState\0";
+pub const kNatronOfxParamRightClickMenu: &'static [u8; 29usize] = b"NatronOfxParamRightClickMenu\0";
+pub const kNatronOfxParamSelectionRectangleState: &'static [u8; 38usize] =
+    b"NatronOfxParamSelectionRectangleState\0";
+pub const kNatronOfxParamCursorName: &'static [u8; 25usize] = b"NatronOfxParamCursorName\0";
+pub const kNatronOfxImageEffectSelectionRectangle: &'static [u8; 39usize] =
+    b"NatronOfxImageEffectSelectionRectangle\0";
+pub const kNatronOfxImageEffectPropDefaultCursors: &'static [u8; 39usize] =
+    b"NatronOfxImageEffectPropDefaultCursors\0";
+pub const kNatronOfxDefaultCursor: &'static [u8; 23usize] = b"NatronOfxDefaultCursor\0";
+pub const kNatronOfxBlankCursor: &'static [u8; 22usize] = b"kNatronOfxBlankCursor\0";
+pub const kNatronOfxArrowCursor: &'static [u8; 21usize] = b"NatronOfxArrowCursor\0";
+pub const kNatronOfxUpArrowCursor: &'static [u8; 23usize] = b"NatronOfxUpArrowCursor\0";
+pub const kNatronOfxCrossCursor: &'static [u8; 21usize] = b"NatronOfxCrossCursor\0";
+pub const kNatronOfxIBeamCursor: &'static [u8; 21usize] = b"NatronOfxIBeamCursor\0";
+pub const kNatronOfxWaitCursor: &'static [u8; 20usize] = b"NatronOfxWaitCursor\0";
+pub const kNatronOfxBusyCursor: &'static [u8; 20usize] = b"NatronOfxBusyCursor\0";
+pub const kNatronOfxForbiddenCursor: &'static [u8; 25usize] = b"NatronOfxForbiddenCursor\0";
+pub const kNatronOfxPointingHandCursor: &'static [u8; 28usize] = b"NatronOfxPointingHandCursor\0";
+pub const kNatronOfxWhatsThisCursor: &'static [u8; 25usize] = b"NatronOfxWhatsThisCursor\0";
+pub const kNatronOfxSizeVerCursor: &'static [u8; 23usize] = b"NatronOfxSizeVerCursor\0";
+pub const kNatronOfxSizeHorCursor: &'static [u8; 23usize] = b"NatronOfxSizeHorCursor\0";
+pub const kNatronOfxSizeBDiagCursor: &'static [u8; 25usize] = b"NatronOfxSizeBDiagCursor\0";
+pub const kNatronOfxSizeFDiagCursor: &'static [u8; 25usize] = b"NatronOfxSizeFDiagCursor\0";
+pub const kNatronOfxSizeAllCursor: &'static [u8; 23usize] = b"NatronOfxSizeAllCursor\0";
+pub const kNatronOfxSplitVCursor: &'static [u8; 22usize] = b"NatronOfxSplitVCursor\0";
+pub const kNatronOfxSplitHCursor: &'static [u8; 22usize] = b"NatronOfxSplitHCursor\0";
+pub const kNatronOfxOpenHandCursor: &'static [u8; 24usize] = b"NatronOfxOpenHandCursor\0";
+pub const kNatronOfxClosedHandCursor: &'static [u8; 26usize] = b"NatronOfxClosedHandCursor\0";
+pub const kNatronOfxImageEffectPropInViewerContextParamsOrder: &'static [u8; 45usize] =
+    b"NatronOfxParamPropInViewerContextParamsOrder\0";
+pub const kNatronOfxParamPropInViewerContextLayoutHint: &'static [u8; 44usize] =
+    b"NatronOfxParamPropInViewerContextLayoutHint\0";
+pub const kNatronOfxParamPropInViewerContextLayoutHintNormal: u32 = 0;
+pub const kNatronOfxParamPropInViewerContextLayoutHintNormalDivider: u32 = 1;
+pub const kNatronOfxParamPropInViewerContextLayoutHintAddNewLine: u32 = 2;
+pub const kNatronOfxParamPropInViewerContextLayoutPadWidth: &'static [u8; 48usize] =
+    b"NatronOfxParamPropInViewerContextLayoutPadWidth\0";
+pub const kNatronOfxParamPropInViewerContextLabel: &'static [u8; 39usize] =
+    b"NatronOfxParamPropInViewerContextLabel\0";
+pub const kNatronOfxParamPropInViewerContextSecret: &'static [u8; 40usize] =
+    b"NatronOfxParamPropInViewerContextSecret\0";
+pub const kNatronOfxBooleanParamPropIsToggableButton: &'static [u8; 42usize] =
+    b"NatronOfxBooleanParamPropIsToggableButton\0";
+pub const kNatronOfxParamPropInViewerContextIsInToolbar: &'static [u8; 47usize] =
+    b"NatronOfxPageParamPropInViewerContextIsToolbar\0";
+pub const kNatronOfxParamPropInViewerContextCanHaveShortcut: &'static [u8; 49usize] =
+    b"NatronOfxParamPropInViewerContextCanHaveShortcut\0";
+pub const kNatronOfxImageEffectPropInViewerContextDefaultShortcuts: &'static [u8; 56usize] =
+    b"NatronOfxImageEffectPropInViewerContextDefaultShortcuts\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutSymbol: &'static [u8; 54usize] =
+    b"NatronOfxImageEffectPropInViewerContextShortcutSymbol\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutHasControlModifier: &'static [u8;
+             66usize] = b"NatronOfxImageEffectPropInViewerContextShortcutHasControlModifier\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutHasShiftModifier: &'static [u8; 64usize] =
+    b"NatronOfxImageEffectPropInViewerContextShortcutHasShiftModifier\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutHasAltModifier: &'static [u8; 62usize] =
+    b"NatronOfxImageEffectPropInViewerContextShortcutHasAltModifier\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutHasMetaModifier: &'static [u8; 63usize] =
+    b"NatronOfxImageEffectPropInViewerContextShortcutHasMetaModifier\0";
+pub const kNatronOfxImageEffectPropInViewerContextShortcutHasKeypadModifier: &'static [u8;
+             65usize] = b"NatronOfxImageEffectPropInViewerContextShortcutHasKeypadModifier\0";
+pub const kNatronOfxImageEffectPropOpenGLContextData: &'static [u8; 42usize] =
+    b"NatronOfxImageEffectPropOpenGLContextData\0";
+pub const kNatronOfxInteractColourPicking: &'static [u8; 31usize] =
+    b"NatronOfxInteractColourPicking\0";
+pub const kNatronOfxPropPickerColour: &'static [u8; 26usize] = b"NatronOfxPropPickerColour\0";
+pub const kNatronOfxParamPropParametricIsPeriodic: &'static [u8; 33usize] =
+    b"OfxParamPropParametricIsPeriodic\0";
+pub const kOfxImageClipPropFormat: &'static [u8; 23usize] = b"OfxImageClipPropFormat\0";
+pub const kOfxImageEffectPropCanDistort: &'static [u8; 29usize] = b"OfxImageEffectPropCanDistort\0";
+pub const kOfxImageEffectActionGetInverseDistortion: &'static [u8; 41usize] =
+    b"OfxImageEffectActionGetInverseDistortion\0";
+pub const kOfxPropInverseDistortionFunction: &'static [u8; 33usize] =
+    b"OfxPropInverseDistortionFunction\0";
+pub const kOfxPropInverseDistortionFunctionData: &'static [u8; 37usize] =
+    b"OfxPropInverseDistortionFunctionData\0";
+pub const kOfxPropInverseDistortionFunctionDataSize: &'static [u8; 41usize] =
+    b"OfxPropInverseDistortionFunctionDataSize\0";
+pub const kOfxPropInverseDistortionDataFreeFunction: &'static [u8; 41usize] =
+    b"OfxPropInverseDistortionDataFreeFunction\0";
+pub const kOfxPropMatrix3x3: &'static [u8; 17usize] = b"OfxPropMatrix3x3\0";
+pub const kOfxInteractPropScreenPixelRatio: &'static [u8; 32usize] =
+    b"OfxInteractPropScreenPixelRatio\0";
+pub type OfxInverseDistortionFunctionV1 = ::std::option::Option<
+    unsafe extern "C" fn(
+        customData: *const ::std::os::raw::c_void,
+        distortedX: f64,
+        distortedY: f64,
+        wantsJacobian: bool,
+        undistortedX: *mut f64,
+        undistortedY: *mut f64,
+        gotJabobian: *mut bool,
+        jacobian: *mut f64,
+    ),
+>;
+pub type OfxInverseDistortionDataFreeFunctionV1 =
+    ::std::option::Option<unsafe extern "C" fn(customData: *mut ::std::os::raw::c_void)>;
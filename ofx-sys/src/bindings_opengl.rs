@@ -0,0 +1,36 @@
+// Pre-generated bindings for the OpenGL render suite, split by hand from the
+// bindgen output as described in bindings.rs, included with the `opengl` feature
+
+pub const kOfxOpenGLRenderSuite: &'static [u8; 32usize] = b"OfxImageEffectOpenGLRenderSuite\0";
+pub const kOfxImageEffectPropOpenGLRenderSupported: &'static [u8; 40usize] =
+    b"OfxImageEffectPropOpenGLRenderSupported\0";
+pub const kOfxOpenGLPropPixelDepth: &'static [u8; 24usize] = b"OfxOpenGLPropPixelDepth\0";
+pub const kOfxImageEffectPropOpenGLEnabled: &'static [u8; 32usize] =
+    b"OfxImageEffectPropOpenGLEnabled\0";
+pub const kOfxImageEffectPropOpenGLTextureIndex: &'static [u8; 37usize] =
+    b"OfxImageEffectPropOpenGLTextureIndex\0";
+pub const kOfxImageEffectPropOpenGLTextureTarget: &'static [u8; 38usize] =
+    b"OfxImageEffectPropOpenGLTextureTarget\0";
+pub const kOfxActionOpenGLContextAttached: &'static [u8; 31usize] =
+    b"OfxActionOpenGLContextAttached\0";
+pub const kOfxActionOpenGLContextDetached: &'static [u8; 32usize] =
+    b"kOfxActionOpenGLContextDetached\0";
+pub const kOfxImageEffectOpenGLRenderSuite: &'static [u8; 32usize] =
+    b"OfxImageEffectOpenGLRenderSuite\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxImageEffectOpenGLRenderSuiteV1 {
+    pub clipLoadTexture: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            format: *const ::std::os::raw::c_char,
+            region: *const OfxRectD,
+            textureHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub clipFreeTexture: ::std::option::Option<
+        unsafe extern "C" fn(textureHandle: OfxPropertySetHandle) -> OfxStatus,
+    >,
+    pub flushResources: ::std::option::Option<unsafe extern "C" fn() -> OfxStatus>,
+}
@@ -0,0 +1,76 @@
+// Pre-generated bindings for parametric parameters, split by hand from the
+// bindgen output as described in bindings.rs, included with the `parametric` feature
+
+pub const kOfxParametricParameterSuite: &'static [u8; 28usize] = b"OfxParametricParameterSuite\0";
+pub const kOfxParamTypeParametric: &'static [u8; 23usize] = b"OfxParamTypeParametric\0";
+pub const kOfxParamPropParametricDimension: &'static [u8; 32usize] =
+    b"OfxParamPropParametricDimension\0";
+pub const kOfxParamPropParametricUIColour: &'static [u8; 31usize] =
+    b"OfxParamPropParametricUIColour\0";
+pub const kOfxParamPropParametricInteractBackground: &'static [u8; 41usize] =
+    b"OfxParamPropParametricInteractBackground\0";
+pub const kOfxParamHostPropSupportsParametricAnimation: &'static [u8; 44usize] =
+    b"OfxParamHostPropSupportsParametricAnimation\0";
+pub const kOfxParamPropParametricRange: &'static [u8; 28usize] = b"OfxParamPropParametricRange\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxParametricParameterSuiteV1 {
+    pub parametricParamGetValue: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            time: OfxTime,
+            parametricPosition: f64,
+            returnValue: *mut f64,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamGetNControlPoints: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            time: f64,
+            returnValue: *mut ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamGetNthControlPoint: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            time: f64,
+            nthCtl: ::std::os::raw::c_int,
+            key: *mut f64,
+            value: *mut f64,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamSetNthControlPoint: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            time: f64,
+            nthCtl: ::std::os::raw::c_int,
+            key: f64,
+            value: f64,
+            addAnimationKey: bool,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamAddControlPoint: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            time: f64,
+            key: f64,
+            value: f64,
+            addAnimationKey: bool,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamDeleteControlPoint: ::std::option::Option<
+        unsafe extern "C" fn(
+            param: OfxParamHandle,
+            curveIndex: ::std::os::raw::c_int,
+            nthCtl: ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub parametricParamDeleteAllControlPoints: ::std::option::Option<
+        unsafe extern "C" fn(param: OfxParamHandle, curveIndex: ::std::os::raw::c_int) -> OfxStatus,
+    >,
+}
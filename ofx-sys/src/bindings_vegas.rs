@@ -0,0 +1,216 @@
+// Pre-generated bindings for the Sony Vegas extensions, split by hand from the
+// bindgen output as described in bindings.rs, included with the `vegas` feature
+
+pub const kOfxSonyVegasHostName: &'static [u8; 31usize] = b"com.sonycreativesoftware.vegas\0";
+pub const kOfxSonyVegasMovieStudioHostName: &'static [u8; 46usize] =
+    b"com.sonycreativesoftware.vegas.moviestudio.hd\0";
+pub const kOfxSonyVegasMovieStudioPlatinumHostName: &'static [u8; 46usize] =
+    b"com.sonycreativesoftware.vegas.moviestudio.pe\0";
+pub const kOfxPropVegasHostAppDataDirectory: &'static [u8; 33usize] =
+    b"OfxPropVegasHostAppDataDirectory\0";
+pub const kOfxParamDoubleTypePolar: &'static [u8; 24usize] = b"OfxParamDoubleTypePolar\0";
+pub const kOfxParamDoubleTypeChrominance: &'static [u8; 30usize] =
+    b"OfxParamDoubleTypeChrominance\0";
+pub const kOfxParamPropColorWheelLevel: &'static [u8; 28usize] = b"OfxParamPropColorWheelLevel\0";
+pub const kOfxParamColorDefaultColorspace: &'static [u8; 31usize] =
+    b"OfxParamColorDefaultColorspace\0";
+pub const kOfxParamColorColorspaceRGB: &'static [u8; 27usize] = b"OfxParamColorColorspaceRGB\0";
+pub const kOfxParamColorColorspaceHSL: &'static [u8; 27usize] = b"OfxParamColorColorspaceHSL\0";
+pub const kOfxParamColorColorspaceHSV: &'static [u8; 27usize] = b"OfxParamColorColorspaceHSV\0";
+pub const kOfxParamColorColorspaceLab: &'static [u8; 27usize] = b"OfxParamColorColorspaceLab\0";
+pub const kOfxParamPropParameterExpanded: &'static [u8; 30usize] =
+    b"OfxParamPropParameterExpanded\0";
+pub const kOfxProbPluginVegasPresetThumbnail: &'static [u8; 34usize] =
+    b"OfxProbPluginVegasPresetThumbnail\0";
+pub const kOfxProbPluginVegasPresetThumbnailDefault: &'static [u8; 41usize] =
+    b"OfxProbPluginVegasPresetThumbnailDefault\0";
+pub const kOfxProbPluginVegasPresetThumbnailSolidImage: &'static [u8; 44usize] =
+    b"OfxProbPluginVegasPresetThumbnailSolidImage\0";
+pub const kOfxProbPluginVegasPresetThumbnailImageWithAlpha: &'static [u8; 48usize] =
+    b"OfxProbPluginVegasPresetThumbnailImageWithAlpha\0";
+pub const kOfxVegasProgressSuite: &'static [u8; 22usize] = b"OfxVegasProgressSuite\0";
+pub const kOfxVegasStereoscopicImageEffectSuite: &'static [u8; 37usize] =
+    b"OfxVegasStereoscopicImageEffectSuite\0";
+pub const kOfxImageEffectPropHelpFile: &'static [u8; 27usize] = b"OfxImageEffectPropHelpFile\0";
+pub const kOfxImageEffectPropHelpContextID: &'static [u8; 32usize] =
+    b"OfxImageEffectPropHelpContextID\0";
+pub const kOfxImageEffectActionInvokeHelp: &'static [u8; 31usize] =
+    b"OfxImageEffectActionInvokeHelp\0";
+pub const kOfxImageEffectActionInvokeAbout: &'static [u8; 32usize] =
+    b"OfxImageEffectActionInvokeAbout\0";
+pub const kOfxImageEffectPropVegasUpliftGUID: &'static [u8; 34usize] =
+    b"OfxImageEffectPropVegasUpliftGUID\0";
+pub const kOfxImageEffectActionVegasKeyframeUplift: &'static [u8; 40usize] =
+    b"OfxImageEffectActionVegasKeyframeUplift\0";
+pub const kOfxPropVegasUpliftKeyframeData: &'static [u8; 31usize] =
+    b"OfxPropVegasUpliftKeyframeData\0";
+pub const kOfxPropVegasUpliftKeyframeDataLength: &'static [u8; 37usize] =
+    b"OfxPropVegasUpliftKeyframeDataLength\0";
+pub const kOfxPropVegasUpliftKeyframeTime: &'static [u8; 31usize] =
+    b"OfxPropVegasUpliftKeyframeTime\0";
+pub const kOfxPropVegasUpliftKeyframeInterpolation: &'static [u8; 40usize] =
+    b"OfxPropVegasUpliftKeyframeInterpolation\0";
+pub const kOfxPropVegasUpliftData: &'static [u8; 23usize] = b"OfxPropVegasUpliftData\0";
+pub const kOfxPropVegasUpliftDataLength: &'static [u8; 29usize] = b"OfxPropVegasUpliftDataLength\0";
+pub const kOfxBitDepthByteBGR: &'static [u8; 19usize] = b"OfxBitDepthByteBGR\0";
+pub const kOfxBitDepthShortBGR: &'static [u8; 20usize] = b"OfxBitDepthShortBGR\0";
+pub const kOfxBitDepthFloatBGR: &'static [u8; 20usize] = b"OfxBitDepthFloatBGR\0";
+pub const kOfxImagePixelOrderRGBA: &'static [u8; 23usize] = b"OfxImagePixelOrderRGBA\0";
+pub const kOfxImagePixelOrderBGRA: &'static [u8; 23usize] = b"OfxImagePixelOrderBGRA\0";
+pub const kOfxImagePropPixelOrder: &'static [u8; 29usize] = b"OfxImageEffectPropPixelOrder\0";
+pub const kOfxImageEffectPropViewsToRender: &'static [u8; 32usize] =
+    b"OfxImageEffectPropViewsToRender\0";
+pub const kOfxImageEffectPropRenderView: &'static [u8; 29usize] = b"OfxImageEffectPropRenderView\0";
+pub const kOfxImageEffectPropRenderQuality: &'static [u8; 32usize] =
+    b"OfxImageEffectPropRenderQuality\0";
+pub const kOfxImageEffectPropRenderQualityPreview: &'static [u8; 39usize] =
+    b"OfxImageEffectPropRenderQualityPreview\0";
+pub const kOfxImageEffectPropRenderQualityGood: &'static [u8; 36usize] =
+    b"OfxImageEffectPropRenderQualityGood\0";
+pub const kOfxImageEffectPropRenderQualityBest: &'static [u8; 36usize] =
+    b"OfxImageEffectPropRenderQualityBest\0";
+pub const kOfxImageEffectPropVegasContext: &'static [u8; 31usize] =
+    b"OfxImageEffectPropVegasContext\0";
+pub const kOfxImageEffectPropVegasContextUnknown: &'static [u8; 38usize] =
+    b"OfxImageEffectPropVegasContextUnknown\0";
+pub const kOfxImageEffectPropVegasContextMedia: &'static [u8; 36usize] =
+    b"OfxImageEffectPropVegasContextMedia\0";
+pub const kOfxImageEffectPropVegasContextTrack: &'static [u8; 36usize] =
+    b"OfxImageEffectPropVegasContextTrack\0";
+pub const kOfxImageEffectPropVegasContextEvent: &'static [u8; 36usize] =
+    b"OfxImageEffectPropVegasContextEvent\0";
+pub const kOfxImageEffectPropVegasContextEventFadeIn: &'static [u8; 42usize] =
+    b"OfxImageEffectPropVegasContextEventFadeIn\0";
+pub const kOfxImageEffectPropVegasContextEventFadeOut: &'static [u8; 43usize] =
+    b"OfxImageEffectPropVegasContextEventFadeOut\0";
+pub const kOfxImageEffectPropVegasContextProject: &'static [u8; 38usize] =
+    b"OfxImageEffectPropVegasContextProject\0";
+pub const kOfxImageEffectPropVegasContextGenerator: &'static [u8; 40usize] =
+    b"OfxImageEffectPropVegasContextGenerator\0";
+pub const kOfxVegasKeyframeSuite: &'static [u8; 22usize] = b"OfxVegasKeyframeSuite\0";
+pub const kOfxVegasKeyframeInterpolationUnknown: &'static [u8; 37usize] =
+    b"OfxVegasKeyframeInterpolationUnknown\0";
+pub const kOfxVegasKeyframeInterpolationLinear: &'static [u8; 36usize] =
+    b"OfxVegasKeyframeInterpolationLinear\0";
+pub const kOfxVegasKeyframeInterpolationFast: &'static [u8; 34usize] =
+    b"OfxVegasKeyframeInterpolationFast\0";
+pub const kOfxVegasKeyframeInterpolationSlow: &'static [u8; 34usize] =
+    b"OfxVegasKeyframeInterpolationSlow\0";
+pub const kOfxVegasKeyframeInterpolationSmooth: &'static [u8; 36usize] =
+    b"OfxVegasKeyframeInterpolationSmooth\0";
+pub const kOfxVegasKeyframeInterpolationSharp: &'static [u8; 35usize] =
+    b"OfxVegasKeyframeInterpolationSharp\0";
+pub const kOfxVegasKeyframeInterpolationHold: &'static [u8; 34usize] =
+    b"OfxVegasKeyframeInterpolationHold\0";
+pub const kOfxVegasKeyframeInterpolationManual: &'static [u8; 36usize] =
+    b"OfxVegasKeyframeInterpolationManual\0";
+pub const kOfxVegasKeyframeInterpolationSplit: &'static [u8; 35usize] =
+    b"OfxVegasKeyframeInterpolationSplit\0";
+pub const kOfxHWndInteractSuite: &'static [u8; 21usize] = b"OfxHWndInteractSuite\0";
+pub const kOfxImageEffectPluginPropHWndInteractV1: &'static [u8; 39usize] =
+    b"OfxImageEffectPluginPropHWndInteractV1\0";
+pub const kOfxHWndInteractPropParent: &'static [u8; 26usize] = b"OfxHWndInteractPropParent\0";
+pub const kOfxHWndInteractPropMinSize: &'static [u8; 27usize] = b"OfxHWndInteractPropMinSize\0";
+pub const kOfxHWndInteractPropPreferredSize: &'static [u8; 33usize] =
+    b"OfxHWndInteractPropPrefferedSize\0";
+pub const kOfxHWndInteractActionCreateWindow: &'static [u8; 34usize] =
+    b"OfxHWndInteractActionCreateWindow\0";
+pub const kOfxHWndInteractPropLocation: &'static [u8; 28usize] = b"OfxHWndInteractPropLocation\0";
+pub const kOfxHWndInteractActionMoveWindow: &'static [u8; 32usize] =
+    b"OfxHWndInteractActionMoveWindow\0";
+pub const kOfxHWndInteractActionDisposeWindow: &'static [u8; 35usize] =
+    b"OfxHWndInteractActionDisposeWindow\0";
+pub const kOfxHWndInteractActionShowWindow: &'static [u8; 32usize] =
+    b"OfxHWndInteractActionShowWindow\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxHWNDInteractSuiteV1 {
+    pub interactGetPropertySet: ::std::option::Option<
+        unsafe extern "C" fn(
+            interactInstance: OfxInteractHandle,
+            property: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+    pub interactUpdate: ::std::option::Option<
+        unsafe extern "C" fn(interactInstance: OfxInteractHandle) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxVegasProgressSuiteV1 {
+    pub progressStart: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            message: *const ::std::os::raw::c_char,
+            messageid: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub progressUpdate: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            progress: f64,
+        ) -> OfxStatus,
+    >,
+    pub progressEnd: ::std::option::Option<
+        unsafe extern "C" fn(effectInstance: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxVegasProgressSuiteV2 {
+    pub progressStart: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            message: *const ::std::os::raw::c_char,
+            messageid: *const ::std::os::raw::c_char,
+            showTimeWindows: ::std::os::raw::c_int,
+        ) -> OfxStatus,
+    >,
+    pub progressUpdate: ::std::option::Option<
+        unsafe extern "C" fn(
+            effectInstance: *mut ::std::os::raw::c_void,
+            progress: f64,
+        ) -> OfxStatus,
+    >,
+    pub progressEnd: ::std::option::Option<
+        unsafe extern "C" fn(effectInstance: *mut ::std::os::raw::c_void) -> OfxStatus,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxVegasStereoscopicImageEffectSuiteV1 {
+    pub clipGetStereoscopicImage: ::std::option::Option<
+        unsafe extern "C" fn(
+            clip: OfxImageClipHandle,
+            time: OfxTime,
+            iview: ::std::os::raw::c_int,
+            region: *mut OfxRectD,
+            imageHandle: *mut OfxPropertySetHandle,
+        ) -> OfxStatus,
+    >,
+}
+pub type OfxVegasStereoscopicImageSuiteV1 = OfxVegasStereoscopicImageEffectSuiteV1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OfxVegasKeyframeSuiteV1 {
+    pub paramGetKeyInterpolation: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            time: OfxTime,
+            interpolationType: *mut *mut ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub paramGetKeySlopes: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime, ...) -> OfxStatus,
+    >,
+    pub paramSetKeyInterpolation: ::std::option::Option<
+        unsafe extern "C" fn(
+            paramHandle: OfxParamHandle,
+            time: OfxTime,
+            interpolationType: *const ::std::os::raw::c_char,
+        ) -> OfxStatus,
+    >,
+    pub paramSetKeySlopes: ::std::option::Option<
+        unsafe extern "C" fn(paramHandle: OfxParamHandle, time: OfxTime, ...) -> OfxStatus,
+    >,
+}
@@ -4,4 +4,20 @@
 #![allow(clippy::const_static_lifetime)]
 #![allow(clippy::unreadable_literal)]
 
+#[cfg(feature = "bindgen")]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(not(feature = "bindgen"))]
+include!("bindings.rs");
+#[cfg(all(feature = "natron", not(feature = "bindgen")))]
+include!("bindings_natron.rs");
+#[cfg(all(feature = "vegas", not(feature = "bindgen")))]
+include!("bindings_vegas.rs");
+#[cfg(all(feature = "opengl", not(feature = "bindgen")))]
+include!("bindings_opengl.rs");
+#[cfg(all(feature = "dialog", not(feature = "bindgen")))]
+include!("bindings_dialog.rs");
+#[cfg(all(feature = "parametric", not(feature = "bindgen")))]
+include!("bindings_parametric.rs");
+#[cfg(all(feature = "camera", not(feature = "bindgen")))]
+include!("bindings_camera.rs");
@@ -0,0 +1,121 @@
+extern crate ofx_sys;
+extern crate phf_codegen;
+
+use ofx_sys::*;
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+type Names = Vec<(&'static [u8], &'static str)>;
+
+macro_rules! names {
+	($($(#[$meta:meta])* $key:ident => $value:ident),* $(,)*) => {{
+		let mut names: Names = Vec::new();
+		$($(#[$meta])* names.push((&$value[..], stringify!($key)));)*
+		names
+	}};
+}
+
+// the maps are keyed by the names without their trailing nul
+fn key(ofx_name: &'static [u8]) -> &'static str {
+	std::str::from_utf8(&ofx_name[..ofx_name.len() - 1]).expect("OpenFX names are ASCII")
+}
+
+fn write_lookup<W: Write>(out: &mut W, enum_name: &str, names: Names) {
+	let mut map = phf_codegen::Map::new();
+	for (ofx_name, variant) in names {
+		map.entry(key(ofx_name), &format!("{}::{}", enum_name, variant));
+	}
+	writeln!(out, "impl {} {{", enum_name).unwrap();
+	writeln!(
+		out,
+		"\tpub(crate) fn from_name(ofx_name: &str) -> Option<Self> {{"
+	)
+	.unwrap();
+	write!(
+		out,
+		"\t\tstatic NAMES: ::phf::Map<&'static str, {}> = ",
+		enum_name
+	)
+	.unwrap();
+	map.build(out).unwrap();
+	writeln!(out, ";\n\t\tNAMES.get(ofx_name).cloned()\n\t}}\n}}\n").unwrap();
+}
+
+// the enum names are shared with enums.rs
+macro_rules! enum_table {
+	($($enum_name:ident {
+		$($(#[$meta:meta])* $key:ident => $value:ident),* $(,)*
+	})*) => {
+		fn write_enum_names<W: Write>(out: &mut W) {
+			$(write_lookup(
+				out,
+				stringify!($enum_name),
+				names! { $($(#[$meta])* $key => $value),* },
+			);)*
+		}
+	};
+}
+
+include!("../src/enum_table.rs");
+
+fn write_action_names<W: Write>(out: &mut W) {
+	write_lookup(
+		out,
+		"GlobalAction",
+		names! {
+			Load => kOfxActionLoad,
+			Describe => kOfxActionDescribe,
+			Unload => kOfxActionUnload,
+			PurgeCaches => kOfxActionPurgeCaches,
+			SyncPrivateData => kOfxActionSyncPrivateData,
+			CreateInstance => kOfxActionCreateInstance,
+			DestroyInstance => kOfxActionDestroyInstance,
+			BeginInstanceChanged => kOfxActionBeginInstanceChanged,
+			InstanceChanged => kOfxActionInstanceChanged,
+			EndInstanceChanged => kOfxActionEndInstanceChanged,
+			BeginInstanceEdit => kOfxActionBeginInstanceEdit,
+			EndInstanceEdit => kOfxActionEndInstanceEdit,
+			#[cfg(feature = "dialog")]
+			Dialog => kOfxActionDialog,
+		},
+	);
+	write_lookup(
+		out,
+		"ImageEffectAction",
+		names! {
+			GetRegionOfDefinition => kOfxImageEffectActionGetRegionOfDefinition,
+			GetRegionsOfInterest => kOfxImageEffectActionGetRegionsOfInterest,
+			GetTimeDomain => kOfxImageEffectActionGetTimeDomain,
+			GetFramesNeeded => kOfxImageEffectActionGetFramesNeeded,
+			GetClipPreferences => kOfxImageEffectActionGetClipPreferences,
+			IsIdentity => kOfxImageEffectActionIsIdentity,
+			BeginSequenceRender => kOfxImageEffectActionBeginSequenceRender,
+			Render => kOfxImageEffectActionRender,
+			EndSequenceRender => kOfxImageEffectActionEndSequenceRender,
+			DescribeInContext => kOfxImageEffectActionDescribeInContext,
+			#[cfg(feature = "natron")]
+			GetInverseDistortion => kOfxImageEffectActionGetInverseDistortion,
+			#[cfg(feature = "vegas")]
+			InvokeHelp => kOfxImageEffectActionInvokeHelp,
+			#[cfg(feature = "vegas")]
+			InvokeAbout => kOfxImageEffectActionInvokeAbout,
+			#[cfg(feature = "vegas")]
+			VegasKeyframeUplift => kOfxImageEffectActionVegasKeyframeUplift,
+			GetClipComponents => kFnOfxImageEffectActionGetClipComponents,
+		},
+	);
+}
+
+// perfect hash maps from the OpenFX names to the enums in enums.rs and
+// action.rs, looked up for every string property and every mainEntry call
+fn main() {
+	let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+	let mut enum_names = BufWriter::new(File::create(out_path.join("enum_names.rs")).unwrap());
+	write_enum_names(&mut enum_names);
+
+	let mut action_names = BufWriter::new(File::create(out_path.join("action_names.rs")).unwrap());
+	write_action_names(&mut action_names);
+}
@@ -9,6 +9,7 @@ use handle::*;
 use ofx_sys::*;
 use plugin::PluginContext;
 use result::*;
+use suites::SuiteKind;
 use types::*;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -44,18 +45,26 @@ pub enum ImageEffectAction {
 	BeginSequenceRender,
 	EndSequenceRender,
 	DescribeInContext,
+	#[cfg(feature = "natron")]
 	GetInverseDistortion,
+	#[cfg(feature = "vegas")]
 	InvokeHelp,
+	#[cfg(feature = "vegas")]
 	InvokeAbout,
+	#[cfg(feature = "vegas")]
 	VegasKeyframeUplift,
+	GetClipComponents,
 }
 
+// from_name is generated by build/main.rs, with a perfect hash of the names
+include!(concat!(env!("OUT_DIR"), "/action_names.rs"));
+
 #[derive(Debug)]
 pub enum Action {
 	Load,
 	Unload,
-	Describe(ImageEffectHandle),
-	DescribeInContext(ImageEffectHandle, DescribeInContextInArgs),
+	Describe(ImageEffectDescriptorHandle),
+	DescribeInContext(ImageEffectDescriptorHandle, DescribeInContextInArgs),
 
 	CreateInstance(ImageEffectHandle),
 	DestroyInstance(ImageEffectHandle),
@@ -86,21 +95,34 @@ pub enum Action {
 	GetClipPreferences(ImageEffectHandle, GetClipPreferencesOutArgs),
 	GetTimeDomain(ImageEffectHandle, GetTimeDomainOutArgs),
 	IsIdentity(ImageEffectHandle, IsIdentityInArgs, IsIdentityOutArgs),
+	GetClipComponents(
+		ImageEffectHandle,
+		GetClipComponentsInArgs,
+		GetClipComponentsOutArgs,
+	),
 
 	GenericGlobal(GlobalAction, GenericPluginHandle),
 	GenericImageEffect(ImageEffectAction, ImageEffectHandle),
 }
 
-pub trait Execute {
-	fn execute(&mut self, context: &PluginContext, action: &mut Action) -> Result<Int> {
+/// Actions of different instances may run concurrently, and the render
+/// action of one instance too unless the plugin says otherwise
+pub trait Execute: Send + Sync {
+	fn execute(&self, context: &PluginContext, action: &mut Action) -> Result<Int> {
 		Ok(eOfxStatus_OK)
 	}
+
+	/// Suites the plugin can't work without, Load fails with
+	/// kOfxStatErrMissingHostFeature if the host does not provide all of them
+	fn required_suites(&self) -> &'static [SuiteKind] {
+		&[]
+	}
 }
 
 pub trait Filter {
-	fn before_execute(&mut self, action: &Action) -> Result<Int>;
+	fn before_execute(&self, action: &Action) -> Result<Int>;
 	fn after_execute(
-		&mut self,
+		&self,
 		context: &PluginContext,
 		action: &mut Action,
 		status: Result<Int>,
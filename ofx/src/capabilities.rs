@@ -0,0 +1,189 @@
+use enums::*;
+use handle::*;
+use ofx_sys::*;
+use plane::ImageComponents;
+use property::*;
+use result::*;
+use std::ffi::CString;
+use std::fmt;
+use types::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuiteVersion {
+	pub name: String,
+	pub version: Int,
+}
+
+/// Snapshot of the host properties and available suites, taken once at Load
+#[derive(Clone, Debug, Default)]
+pub struct HostCapabilities {
+	pub name: String,
+	pub label: String,
+	pub version: Vec<Int>,
+	pub version_label: String,
+	pub is_background: Bool,
+	pub supports_overlays: Bool,
+	pub supports_multi_resolution: Bool,
+	pub supports_tiles: Bool,
+	pub temporal_clip_access: Bool,
+	pub supported_components: Vec<ImageComponents>,
+	pub supported_contexts: Vec<ImageEffectContext>,
+	pub supported_pixel_depths: Vec<BitDepth>,
+	pub supports_multiple_clip_depths: Bool,
+	pub supports_multiple_clip_pars: Bool,
+	pub setable_frame_rate: Bool,
+	pub setable_fielding: Bool,
+	pub supports_custom_interact: Bool,
+	pub supports_string_animation: Bool,
+	pub supports_choice_animation: Bool,
+	pub supports_boolean_animation: Bool,
+	pub supports_custom_animation: Bool,
+	#[cfg(feature = "parametric")]
+	pub supports_parametric_animation: Bool,
+	pub max_parameters: Int,
+	pub max_pages: Int,
+	pub sequential_render: Bool,
+	#[cfg(feature = "opengl")]
+	pub opengl_render_supported: Bool,
+	pub render_quality_draft: Bool,
+	pub native_origin: Option<HostNativeOrigin>,
+	pub multi_planar: Bool,
+	pub suites: Vec<SuiteVersion>,
+}
+
+// not every host sets every property, missing ones are left at their default
+// and values unknown to this library are skipped
+fn get_all_known<P, T, F>(host: &ImageEffectHost, convert: F) -> Vec<T>
+where
+	P: Named + Get<ReturnType = CString>,
+	F: Fn(&CString) -> Option<T>,
+{
+	host.get_all::<P>()
+		.unwrap_or_default()
+		.iter()
+		.filter_map(convert)
+		.collect()
+}
+
+impl HostCapabilities {
+	pub fn new(host: &ImageEffectHost, suites: Vec<SuiteVersion>) -> Self {
+		HostCapabilities {
+			name: host.get_name().unwrap_or_default(),
+			label: host.get_label().unwrap_or_default(),
+			version: host.get_all::<Version::Property>().unwrap_or_default(),
+			version_label: host.get_version_label().unwrap_or_default(),
+			is_background: host.get_is_background().unwrap_or_default(),
+			supports_overlays: host.get_supports_overlays().unwrap_or_default(),
+			supports_multi_resolution: host.get_supports_multi_resolution().unwrap_or_default(),
+			supports_tiles: host.get_supports_tiles().unwrap_or_default(),
+			temporal_clip_access: host.get_temporal_clip_access().unwrap_or_default(),
+			supported_components: get_all_known::<SupportedComponents::Property, _, _>(
+				host,
+				|value| ImageComponents::from_cstring(value),
+			),
+			supported_contexts: get_all_known::<SupportedContexts::Property, _, _>(host, |value| {
+				ImageEffectContext::from_cstring(value)
+			}),
+			supported_pixel_depths: get_all_known::<SupportedPixelDepths::Property, _, _>(
+				host,
+				|value| BitDepth::from_cstring(value),
+			),
+			supports_multiple_clip_depths: host
+				.get_supports_multiple_clip_depths()
+				.unwrap_or_default(),
+			supports_multiple_clip_pars: host.get_supports_multiple_clip_pars().unwrap_or_default(),
+			setable_frame_rate: host.get_setable_frame_rate().unwrap_or_default(),
+			setable_fielding: host.get_setable_fielding().unwrap_or_default(),
+			supports_custom_interact: host.get_supports_custom_interact().unwrap_or_default(),
+			supports_string_animation: host.get_supports_string_animation().unwrap_or_default(),
+			supports_choice_animation: host.get_supports_choice_animation().unwrap_or_default(),
+			supports_boolean_animation: host.get_supports_boolean_animation().unwrap_or_default(),
+			supports_custom_animation: host.get_supports_custom_animation().unwrap_or_default(),
+			#[cfg(feature = "parametric")]
+			supports_parametric_animation: host
+				.get_supports_parametric_animation()
+				.unwrap_or_default(),
+			max_parameters: host.get_max_parameters().unwrap_or_default(),
+			max_pages: host.get_max_pages().unwrap_or_default(),
+			sequential_render: host.get_sequential_render().unwrap_or_default(),
+			// on the host this is a string, "false", "true" or "needed"
+			#[cfg(feature = "opengl")]
+			opengl_render_supported: host
+				.get_raw::<String, _>(&kOfxImageEffectPropOpenGLRenderSupported[..])
+				.map(|value| value != "false")
+				.unwrap_or_default(),
+			render_quality_draft: host.get_render_quality_draft().unwrap_or_default(),
+			native_origin: host.get_native_origin().ok(),
+			multi_planar: host.get_multi_planar().unwrap_or_default(),
+			suites,
+		}
+	}
+
+	pub fn supports_context(&self, context: ImageEffectContext) -> bool {
+		self.supported_contexts.contains(&context)
+	}
+
+	pub fn supports_component(&self, component: ImageComponent) -> bool {
+		self.supported_components
+			.iter()
+			.any(|supported| *supported == component)
+	}
+
+	pub fn supports_pixel_depth(&self, depth: BitDepth) -> bool {
+		self.supported_pixel_depths.contains(&depth)
+	}
+
+	pub fn suite_version(&self, name: &str) -> Option<Int> {
+		self.suites
+			.iter()
+			.filter(|suite| suite.name == name)
+			.map(|suite| suite.version)
+			.max()
+	}
+
+	pub fn has_suite(&self, name: &str) -> bool {
+		self.suite_version(name).is_some()
+	}
+}
+
+impl fmt::Display for SuiteVersion {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}/v{}", self.name, self.version)
+	}
+}
+
+impl fmt::Display for HostCapabilities {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let version: Vec<String> = self.version.iter().map(|v| v.to_string()).collect();
+		let components: Vec<String> = self
+			.supported_components
+			.iter()
+			.map(|c| c.to_string())
+			.collect();
+		let suites: Vec<String> = self.suites.iter().map(|s| s.to_string()).collect();
+		write!(
+			f,
+			"host:{} label:{:?} version:{} ({}) contexts:{:?} components:[{}] depths:{:?} \
+			 tiles:{} multires:{} temporal:{} max_params:{} max_pages:{} \
+			 origin:{:?} multiplanar:{} suites:[{}]",
+			self.name,
+			self.label,
+			version.join("."),
+			self.version_label,
+			self.supported_contexts,
+			components.join(", "),
+			self.supported_pixel_depths,
+			self.supports_tiles,
+			self.supports_multi_resolution,
+			self.temporal_clip_access,
+			self.max_parameters,
+			self.max_pages,
+			self.native_origin,
+			self.multi_planar,
+			suites.join(", ")
+		)?;
+		#[cfg(feature = "opengl")]
+		write!(f, " opengl:{}", self.opengl_render_supported)?;
+		Ok(())
+	}
+}
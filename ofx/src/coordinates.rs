@@ -0,0 +1,252 @@
+use enums::ImageField;
+use types::*;
+
+// canonical coordinates are pixels of the full resolution, square pixel frame,
+// pixel coordinates are scaled by the render scale and squeezed by the
+// pixel aspect ratio along x
+
+/// Images of a single field have half the rows of the frame
+pub fn field_render_scale(render_scale: PointD, field: ImageField) -> PointD {
+	match field {
+		ImageField::Lower | ImageField::Upper => PointD {
+			x: render_scale.x,
+			y: render_scale.y * 0.5,
+		},
+		ImageField::None | ImageField::Both => render_scale,
+	}
+}
+
+pub trait PointDExt {
+	fn to_pixels(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> PointD;
+	fn to_canonical(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> PointD;
+}
+
+impl PointDExt for PointD {
+	fn to_pixels(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> PointD {
+		PointD {
+			x: self.x * render_scale.x / pixel_aspect_ratio,
+			y: self.y * render_scale.y,
+		}
+	}
+
+	fn to_canonical(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> PointD {
+		PointD {
+			x: self.x * pixel_aspect_ratio / render_scale.x,
+			y: self.y / render_scale.y,
+		}
+	}
+}
+
+/// Empty rectangles have x2 <= x1 or y2 <= y1, intersections of disjoint
+/// rectangles are empty and anchored at the corner of the first one
+pub trait RectDExt {
+	fn width(&self) -> Double;
+	fn height(&self) -> Double;
+	fn is_empty(&self) -> bool;
+	fn contains(&self, point: PointD) -> bool;
+	fn intersection(&self, other: &RectD) -> RectD;
+	fn union(&self, other: &RectD) -> RectD;
+	fn clamp(&self, point: PointD) -> PointD;
+	fn expand(&self, radius: PointD) -> RectD;
+	/// Grows by a radius given in pixels at the render scale
+	fn expand_by_pixels(
+		&self,
+		radius: PointD,
+		render_scale: PointD,
+		pixel_aspect_ratio: Double,
+	) -> RectD;
+	/// Rounds out to the smallest pixel rectangle covering this one
+	fn to_pixels(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> RectI;
+}
+
+pub trait RectIExt {
+	fn width(&self) -> Int;
+	fn height(&self) -> Int;
+	fn is_empty(&self) -> bool;
+	fn contains(&self, point: PointI) -> bool;
+	fn intersection(&self, other: &RectI) -> RectI;
+	fn union(&self, other: &RectI) -> RectI;
+	fn clamp(&self, point: PointI) -> PointI;
+	fn expand(&self, radius: PointI) -> RectI;
+	fn to_canonical(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> RectD;
+}
+
+macro_rules! rect_ext_common {
+	($rect:ident, $point:ident, $coord:ty) => {
+		fn width(&self) -> $coord {
+			self.x2 - self.x1
+		}
+
+		fn height(&self) -> $coord {
+			self.y2 - self.y1
+		}
+
+		fn is_empty(&self) -> bool {
+			self.x2 <= self.x1 || self.y2 <= self.y1
+		}
+
+		fn contains(&self, point: $point) -> bool {
+			point.x >= self.x1 && point.x < self.x2 && point.y >= self.y1 && point.y < self.y2
+		}
+
+		fn intersection(&self, other: &$rect) -> $rect {
+			let x1 = self.x1.max(other.x1);
+			let y1 = self.y1.max(other.y1);
+			$rect {
+				x1,
+				y1,
+				x2: self.x2.min(other.x2).max(x1),
+				y2: self.y2.min(other.y2).max(y1),
+			}
+		}
+
+		fn union(&self, other: &$rect) -> $rect {
+			if other.is_empty() {
+				*self
+			} else if self.is_empty() {
+				*other
+			} else {
+				$rect {
+					x1: self.x1.min(other.x1),
+					y1: self.y1.min(other.y1),
+					x2: self.x2.max(other.x2),
+					y2: self.y2.max(other.y2),
+				}
+			}
+		}
+
+		fn clamp(&self, point: $point) -> $point {
+			$point {
+				x: point.x.max(self.x1).min(self.x2),
+				y: point.y.max(self.y1).min(self.y2),
+			}
+		}
+
+		fn expand(&self, radius: $point) -> $rect {
+			$rect {
+				x1: self.x1 - radius.x,
+				y1: self.y1 - radius.y,
+				x2: self.x2 + radius.x,
+				y2: self.y2 + radius.y,
+			}
+		}
+	};
+}
+
+impl RectDExt for RectD {
+	rect_ext_common!(RectD, PointD, Double);
+
+	fn expand_by_pixels(
+		&self,
+		radius: PointD,
+		render_scale: PointD,
+		pixel_aspect_ratio: Double,
+	) -> RectD {
+		self.expand(radius.to_canonical(render_scale, pixel_aspect_ratio))
+	}
+
+	fn to_pixels(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> RectI {
+		let min = PointD {
+			x: self.x1,
+			y: self.y1,
+		}
+		.to_pixels(render_scale, pixel_aspect_ratio);
+		let max = PointD {
+			x: self.x2,
+			y: self.y2,
+		}
+		.to_pixels(render_scale, pixel_aspect_ratio);
+		RectI {
+			x1: min.x.floor() as Int,
+			y1: min.y.floor() as Int,
+			x2: max.x.ceil() as Int,
+			y2: max.y.ceil() as Int,
+		}
+	}
+}
+
+impl RectIExt for RectI {
+	rect_ext_common!(RectI, PointI, Int);
+
+	fn to_canonical(&self, render_scale: PointD, pixel_aspect_ratio: Double) -> RectD {
+		let min = PointD {
+			x: Double::from(self.x1),
+			y: Double::from(self.y1),
+		}
+		.to_canonical(render_scale, pixel_aspect_ratio);
+		let max = PointD {
+			x: Double::from(self.x2),
+			y: Double::from(self.y2),
+		}
+		.to_canonical(render_scale, pixel_aspect_ratio);
+		RectD {
+			x1: min.x,
+			y1: min.y,
+			x2: max.x,
+			y2: max.y,
+		}
+	}
+}
+
+mod tests {
+	use super::*;
+
+	const HALF_SCALE: PointD = PointD { x: 0.5, y: 0.5 };
+
+	fn rect(x1: Int, y1: Int, x2: Int, y2: Int) -> RectI {
+		RectI { x1, y1, x2, y2 }
+	}
+
+	fn same(a: RectI, b: RectI) -> bool {
+		(a.x1, a.y1, a.x2, a.y2) == (b.x1, b.y1, b.x2, b.y2)
+	}
+
+	#[test]
+	fn canonical_to_pixels_rounds_out() {
+		let canonical = RectD {
+			x1: -1.0,
+			y1: 0.0,
+			x2: 101.0,
+			y2: 51.0,
+		};
+		let pixels = canonical.to_pixels(HALF_SCALE, 2.0);
+		assert!(same(pixels, rect(-1, 0, 26, 26)));
+		let round_trip = pixels.to_canonical(HALF_SCALE, 2.0);
+		assert!(round_trip.x1 <= canonical.x1 && round_trip.x2 >= canonical.x2);
+		assert!(round_trip.y1 <= canonical.y1 && round_trip.y2 >= canonical.y2);
+	}
+
+	#[test]
+	fn intersection_and_union() {
+		let a = rect(0, 0, 10, 10);
+		let b = rect(5, 5, 20, 20);
+		let far = rect(30, 30, 40, 40);
+		assert!(same(a.intersection(&b), rect(5, 5, 10, 10)));
+		assert!(a.intersection(&far).is_empty());
+		assert!(same(a.union(&b), rect(0, 0, 20, 20)));
+		assert!(same(a.union(&a.intersection(&far)), a));
+		let clamped = a.clamp(PointI { x: -3, y: 12 });
+		assert!(clamped.x == 0 && clamped.y == 10);
+	}
+
+	#[test]
+	fn pixel_radius_is_scaled_to_canonical() {
+		let roi = RectD {
+			x1: 0.0,
+			y1: 0.0,
+			x2: 100.0,
+			y2: 100.0,
+		};
+		let expanded = roi.expand_by_pixels(PointD { x: 2.0, y: 2.0 }, HALF_SCALE, 2.0);
+		assert!(expanded.x1 == -8.0 && expanded.x2 == 108.0);
+		assert!(expanded.y1 == -4.0 && expanded.y2 == 104.0);
+	}
+
+	#[test]
+	fn fields_halve_the_rows() {
+		let scale = field_render_scale(HALF_SCALE, ImageField::Lower);
+		assert!(scale.x == 0.5 && scale.y == 0.25);
+		let scale = field_render_scale(HALF_SCALE, ImageField::Both);
+		assert!(scale.x == 0.5 && scale.y == 0.5);
+	}
+}
@@ -0,0 +1,130 @@
+// the OpenFX names of the string enums, expanded into the enums by enums.rs
+// and into their perfect hash lookups by build/main.rs
+enum_table! {
+	Type {
+		ImageEffectHost => kOfxTypeImageEffectHost,
+		ImageEffect => kOfxTypeImageEffect,
+		ImageEffectInstance => kOfxTypeImageEffectInstance,
+		Parameter => kOfxTypeParameter,
+		ParameterInstance => kOfxTypeParameterInstance,
+		Clip => kOfxTypeClip,
+		Image => kOfxTypeImage,
+	}
+
+	ImageEffectContext {
+		Filter => kOfxImageEffectContextFilter,
+		General => kOfxImageEffectContextGeneral,
+	}
+
+	BitDepth {
+		Byte => kOfxBitDepthByte,
+		Short => kOfxBitDepthShort,
+		Float => kOfxBitDepthFloat,
+	}
+
+	ImageComponent {
+		None => kOfxImageComponentNone,
+		RGBA => kOfxImageComponentRGBA,
+		RGB => kOfxImageComponentRGB,
+		Alpha => kOfxImageComponentAlpha,
+		YUVA => kOfxImageComponentYUVA,
+		#[cfg(feature = "natron")]
+		XY => kNatronOfxImageComponentXY,
+		MotionVectors => kFnOfxImageComponentMotionVectors,
+		StereoDisparity => kFnOfxImageComponentStereoDisparity,
+	}
+
+	ParamType {
+		Integer => kOfxParamTypeInteger,
+		Double => kOfxParamTypeDouble,
+		Boolean => kOfxParamTypeBoolean,
+		Choice => kOfxParamTypeChoice,
+		RGBA => kOfxParamTypeRGBA,
+		RGB => kOfxParamTypeRGB,
+		Integer2D => kOfxParamTypeInteger2D,
+		Double2D => kOfxParamTypeDouble2D,
+		Double3D => kOfxParamTypeDouble3D,
+		Integer3D => kOfxParamTypeInteger3D,
+		String => kOfxParamTypeString,
+		Custom => kOfxParamTypeCustom,
+		Group => kOfxParamTypeGroup,
+		Page => kOfxParamTypePage,
+		PushButton => kOfxParamTypePushButton,
+	}
+
+	ParamDoubleType {
+		Plain => kOfxParamDoubleTypePlain,
+		Angle => kOfxParamDoubleTypeAngle,
+		Scale => kOfxParamDoubleTypeScale,
+		Time => kOfxParamDoubleTypeTime,
+		AbsoluteTime => kOfxParamDoubleTypeAbsoluteTime,
+		X => kOfxParamDoubleTypeX,
+		XAbsolute => kOfxParamDoubleTypeXAbsolute,
+		Y => kOfxParamDoubleTypeY,
+		YAbsolute => kOfxParamDoubleTypeYAbsolute,
+		XY => kOfxParamDoubleTypeXY,
+		XYAbsolute => kOfxParamDoubleTypeXYAbsolute,
+	}
+
+	ImageField {
+		None => kOfxImageFieldNone,
+		Both => kOfxImageFieldBoth,
+		Lower => kOfxImageFieldLower,
+		Upper => kOfxImageFieldUpper,
+	}
+
+	ImageFieldOrder {
+		None => kOfxImageFieldNone,
+		Lower => kOfxImageFieldLower,
+		Upper => kOfxImageFieldUpper,
+	}
+
+	ImageFieldExtraction {
+		Both => kOfxImageFieldBoth,
+		Single => kOfxImageFieldSingle,
+		Doubled => kOfxImageFieldDoubled,
+	}
+
+	Image {
+		Opaque => kOfxImageOpaque,
+		PreMultiplied => kOfxImagePreMultiplied,
+		UnPreMultiplied => kOfxImageUnPreMultiplied,
+	}
+
+	ParamString {
+		IsSingleLine => kOfxParamStringIsSingleLine,
+		IsMultiLine => kOfxParamStringIsMultiLine,
+		IsFilePath => kOfxParamStringIsFilePath,
+		IsDirectoryPath => kOfxParamStringIsDirectoryPath,
+		IsLabel => kOfxParamStringIsLabel,
+		IsRichTextFormat => kOfxParamStringIsRichTextFormat,
+	}
+
+	HostNativeOrigin {
+		BottomLeft => kOfxHostNativeOriginBottomLeft,
+		TopLeft => kOfxHostNativeOriginTopLeft,
+		Center => kOfxHostNativeOriginCenter,
+	}
+
+	ImageEffectRender {
+		Unsafe => kOfxImageEffectRenderUnsafe,
+		InstanceSafe => kOfxImageEffectRenderInstanceSafe,
+		FullySafe => kOfxImageEffectRenderFullySafe,
+	}
+
+	Change {
+		UserEdited => kOfxChangeUserEdited,
+		PluginEdited => kOfxChangePluginEdited,
+		Time => kOfxChangeTime,
+	}
+
+	ParamInvalidate {
+		All => kOfxParamInvalidateAll,
+		ValueChangeToEnd => kOfxParamInvalidateValueChangeToEnd,
+	}
+
+	ParamCoordinates {
+		Canonical => kOfxParamCoordinatesCanonical,
+		Normalised => kOfxParamCoordinatesNormalised,
+	}
+}
@@ -12,80 +12,66 @@ pub trait IdentifiedEnum: Sized {
 	}
 }
 
-// TODO allow mixing
+// from_name is generated by build/main.rs, with a perfect hash of the names
+include!(concat!(env!("OUT_DIR"), "/enum_names.rs"));
+
+fn ofx_name_str(ofx_name: &[u8]) -> Option<&str> {
+	let ofx_name = match ofx_name.split_last() {
+		Some((&0, name)) => name,
+		_ => ofx_name,
+	};
+	std::str::from_utf8(ofx_name).ok()
+}
+
 macro_rules! identified_enum {
 	($visibility:vis enum $name:ident {
-		$($key:ident => $value:ident),
+		$($(#[$meta:meta])* $key:ident => $value:ident),
 		*
 	}) =>
 	{
-		#[derive(Copy, Clone, Debug)]
-		$visibility enum $name {
-			$($key),
-			*
-		}
-
-		impl IdentifiedEnum for $name {
-			fn to_bytes(&self) -> &'static [u8] {
-				match *self {
-					$($name::$key => $value),
-					*
-				}
-			}
-
-			fn from_bytes(ofx_name: &[u8]) -> Option<Self> {
-				$(if ofx_name == $value { Some($name::$key) } else)
-				*
-				{ None }
-			}
-		}
-	};
-	($visibility:vis enum $name:ident {
-		$($key:ident),
-		*
-	}) => {
 		#[derive(Copy, Clone, Debug, PartialEq)]
 		$visibility enum $name {
-			$($key),
+			$($(#[$meta])* $key),
 			*
 		}
 
 		impl IdentifiedEnum for $name {
 			fn to_bytes(&self) -> &'static [u8] {
 				match *self {
-					$($name::$key => concat_idents!(kOfx, $name, $key)),
+					$($(#[$meta])* $name::$key => $value),
 					*
 				}
 			}
 
 			fn from_bytes(ofx_name: &[u8]) -> Option<Self> {
-				// TODO: use PHF or some sort of hashing
-				$(if ofx_name == &concat_idents!(kOfx, $name, $key)[..] { Some($name::$key) } else)
+				// hosts often hand back the very strings the plugin passed them
+				$($(#[$meta])*
+				{
+					if std::ptr::eq(ofx_name, &$value[..]) {
+						return Some($name::$key);
+					}
+				})
 				*
-				{ None }
+				$name::from_name(ofx_name_str(ofx_name)?)
 			}
 		}
-	}
+	};
 }
 
-identified_enum! {
-	pub enum Type {
-		ImageEffectHost,
-		ImageEffect,
-		ImageEffectInstance,
-		Parameter,
-		ParameterInstance,
-		Clip,
-		Image
-	}
+macro_rules! enum_table {
+	($($enum_name:ident {
+		$($(#[$meta:meta])* $key:ident => $value:ident),* $(,)*
+	})*) => {
+		$(identified_enum! {
+			pub enum $enum_name {
+				$($(#[$meta])* $key => $value),*
+			}
+		})*
+	};
 }
 
-identified_enum! {
-	pub enum ImageEffectContext {
-		Filter,
-		General
-	}
-}
+// the names are shared with build/main.rs
+include!("enum_table.rs");
 
 impl ImageEffectContext {
 	pub fn is_general(self) -> bool {
@@ -93,14 +79,6 @@ impl ImageEffectContext {
 	}
 }
 
-identified_enum! {
-	pub enum BitDepth {
-		Byte,
-		Short,
-		Float
-	}
-}
-
 impl BitDepth {
 	pub fn bits(self) -> usize {
 		match self {
@@ -111,132 +89,59 @@ impl BitDepth {
 	}
 }
 
-identified_enum! {
-	pub enum ImageComponent {
-		RGBA,
-		RGB,
-		Alpha
-	}
-}
-
 impl ImageComponent {
 	pub fn is_alpha(self) -> bool {
 		self == ImageComponent::Alpha
 	}
 
 	pub fn is_rgb(self) -> bool {
-		!self.is_alpha()
-	}
-}
-
-identified_enum! {
-	pub enum ParamType {
-		Integer,
-		Double,
-		Boolean,
-		Choice,
-		RGBA,
-		RGB,
-		Integer2D,
-		Double2D,
-		Double3D,
-		Integer3D,
-		String,
-		Custom,
-		Group,
-		Page,
-		PushButton
-	}
-}
-
-identified_enum! {
-	pub enum ParamDoubleType {
-		Plain,
-		Angle,
-		Scale,
-		Time,
-		AbsoluteTime,
-		X,
-		XAbsolute,
-		Y,
-		YAbsolute,
-		XY,
-		XYAbsolute
-	}
-}
-
-identified_enum! {
-	pub enum ImageField {
-		None,
-		Both,
-		Lower,
-		Upper
+		self == ImageComponent::RGBA || self == ImageComponent::RGB
 	}
-}
 
-identified_enum! {
-	pub enum ImageFieldOrder {
-		None => kOfxImageFieldNone,
-		Lower => kOfxImageFieldLower,
-		Upper => kOfxImageFieldUpper
-	}
-}
-
-identified_enum! {
-	pub enum ImageFieldExtraction {
-		Both => kOfxImageFieldBoth,
-		Single => kOfxImageFieldSingle,
-		Doubled => kOfxImageFieldDoubled
-	}
-}
-
-identified_enum! {
-	pub enum Image {
-		Opaque,
-		PreMultiplied,
-		UnPreMultiplied
-	}
-}
-
-identified_enum! {
-	pub enum ParamString {
-		IsSingleLine,
-		IsMultiLine,
-		IsFilePath,
-		IsDirectoryPath,
-		IsLabel,
-		IsRichTextFormat
-	}
-}
-
-identified_enum! {
-	pub enum HostNativeOrigin {
-		BottomLeft,
-		TopLeft,
-		Center
+	pub fn num_channels(self) -> usize {
+		match self {
+			ImageComponent::None => 0,
+			ImageComponent::Alpha => 1,
+			#[cfg(feature = "natron")]
+			ImageComponent::XY => 2,
+			ImageComponent::MotionVectors | ImageComponent::StereoDisparity => 2,
+			ImageComponent::RGB => 3,
+			ImageComponent::RGBA | ImageComponent::YUVA => 4,
+		}
 	}
 }
 
-identified_enum! {
-	pub enum ImageEffectRender {
-		Unsafe,
-		InstanceSafe,
-		FullySafe
-	}
+// camera projection modes are doubles, not strings
+#[cfg(feature = "camera")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CameraProjectionMode {
+	Perspective,
+	Orthographic,
+	UV,
+	Spherical,
 }
 
-identified_enum! {
-	pub enum Change {
-		UserEdited,
-		PluginEdited,
-		Time
+#[cfg(feature = "camera")]
+impl CameraProjectionMode {
+	pub fn to_double(self) -> f64 {
+		match self {
+			CameraProjectionMode::Perspective => kNukeOfxCameraProjectionModePerspective,
+			CameraProjectionMode::Orthographic => kNukeOfxCameraProjectionModeOrthographic,
+			CameraProjectionMode::UV => kNukeOfxCameraProjectionModeUV,
+			CameraProjectionMode::Spherical => kNukeOfxCameraProjectionModeSpherical,
+		}
 	}
-}
 
-identified_enum! {
-	pub enum ParamInvalidate {
-		All,
-		ValueChangeToEnd
+	pub fn from_double(value: f64) -> Option<Self> {
+		[
+			CameraProjectionMode::Perspective,
+			CameraProjectionMode::Orthographic,
+			CameraProjectionMode::UV,
+			CameraProjectionMode::Spherical,
+		]
+		.iter()
+		.cloned()
+		.find(|mode| (mode.to_double() - value).abs() < 0.5)
 	}
 }
 
@@ -267,4 +172,39 @@ mod tests {
 		assert!(ImageEffectContext::from_cstring(&str_value) == Some(ImageEffectContext::General));
 	}
 
+	#[test]
+	fn from_copied_names() {
+		let copied = kOfxImageComponentRGBA.to_vec();
+		assert!(ImageComponent::from_bytes(&copied) == Some(ImageComponent::RGBA));
+		assert!(
+			ImageComponent::from_bytes(&copied[..copied.len() - 1]) == Some(ImageComponent::RGBA)
+		);
+		assert!(ImageFieldOrder::from_bytes(kOfxImageFieldNone) == Some(ImageFieldOrder::None));
+		assert!(ImageFieldOrder::from_bytes(kOfxImageFieldBoth) == None);
+		assert!(ParamType::from_bytes(b"OfxParamTypeUnknown\0") == None);
+	}
+
+	// copies skip the pointer comparison, so every name goes through from_name
+	#[test]
+	fn every_name_round_trips() {
+		macro_rules! enum_table {
+			($($enum_name:ident {
+				$($(#[$meta:meta])* $key:ident => $value:ident),* $(,)*
+			})*) => {{
+				$($($(#[$meta])* {
+					let copied = $value.to_vec();
+					assert!($enum_name::from_bytes(&copied) == Some($enum_name::$key));
+					assert!($enum_name::$key.to_bytes() == $value);
+				})*)*
+			}};
+		}
+		include!("enum_table.rs");
+	}
+
+	#[cfg(feature = "camera")]
+	#[test]
+	fn camera_projection_mode_from_double() {
+		assert!(CameraProjectionMode::from_double(1.0) == Some(CameraProjectionMode::Orthographic));
+		assert!(CameraProjectionMode::from_double(7.0) == None);
+	}
 }
@@ -1,24 +1,29 @@
+use coordinates::*;
 use enums::*;
 use image::*;
+use memory::*;
 use ofx_sys::*;
+use plane::*;
 use property::*;
 use result::*;
 use std::borrow::Borrow;
-use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::marker::PhantomData;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use suites::*;
 use types::*;
+use util::*;
 
 #[derive(Debug, Clone)]
 pub struct PropertySetHandle {
 	inner: OfxPropertySetHandle,
-	property: Rc<OfxPropertySuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
 }
 
 impl PropertySetHandle {
-	pub(crate) fn new(inner: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> Self {
+	pub(crate) fn new(inner: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> Self {
 		PropertySetHandle { inner, property }
 	}
 
@@ -26,7 +31,7 @@ impl PropertySetHandle {
 		panic!("Do not use, only for type validation testing");
 		PropertySetHandle {
 			inner: std::ptr::null::<OfxPropertySetStruct>() as *mut _,
-			property: unsafe { Rc::new(*std::ptr::null()) },
+			property: unsafe { Arc::new(*std::ptr::null()) },
 		}
 	}
 }
@@ -40,11 +45,11 @@ pub struct GenericPluginHandle {
 #[derive(Clone)]
 pub struct ImageEffectHost {
 	inner: OfxPropertySetHandle,
-	property: Rc<OfxPropertySuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
 }
 
 impl ImageEffectHost {
-	pub fn new(host: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> Self {
+	pub fn new(host: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> Self {
 		ImageEffectHost {
 			inner: host,
 			property,
@@ -55,24 +60,53 @@ impl ImageEffectHost {
 #[derive(Clone)]
 pub struct ImageEffectHandle {
 	inner: OfxImageEffectHandle,
-	property: Rc<OfxPropertySuiteV1>,
-	image_effect: Rc<OfxImageEffectSuiteV1>,
-	parameter: Rc<OfxParameterSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
+	image_effect_plane: Option<ImageEffectPlaneSuite>,
+	handle_cache: Option<Arc<HandleCache>>,
+	#[cfg(feature = "camera")]
+	camera: Option<Arc<NukeOfxCameraSuiteV1>>,
+}
+
+/// The effect in the describe actions, the only ones where clips and
+/// parameters can be defined and the effect descriptor written
+#[derive(Clone)]
+pub struct ImageEffectDescriptorHandle {
+	inner: OfxImageEffectHandle,
+	property: Arc<OfxPropertySuiteV1>,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
+	// clips defined so far, shared by the clones handed to the plugin
+	clip_names: Arc<Mutex<Vec<CString>>>,
+	#[cfg(feature = "camera")]
+	camera: Option<Arc<NukeOfxCameraSuiteV1>>,
 }
 
 #[derive(Clone)]
 pub struct ClipInstance {
 	inner: OfxImageClipHandle,
 	inner_properties: OfxPropertySetHandle,
-	property: Rc<OfxPropertySuiteV1>,
-	image_effect: Rc<OfxImageEffectSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
+	image_effect_plane: Option<ImageEffectPlaneSuite>,
+}
+
+#[cfg(feature = "camera")]
+#[derive(Clone)]
+pub struct CameraInstance {
+	inner: NukeOfxCameraHandle,
+	inner_properties: OfxPropertySetHandle,
+	property: Arc<OfxPropertySuiteV1>,
+	camera: Arc<NukeOfxCameraSuiteV1>,
+	view: Int,
 }
 
 #[derive(Clone)]
 pub struct Image {
 	inner: OfxPropertySetHandle,
-	property: Rc<OfxPropertySuiteV1>,
-	image_effect: Rc<OfxImageEffectSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
 }
 
 pub trait ParamHandleValue: Default + Clone {}
@@ -91,18 +125,66 @@ where
 {
 	inner: OfxParamHandle,
 	inner_properties: OfxPropertySetHandle,
-	property: Rc<OfxPropertySuiteV1>,
-	parameter: Rc<OfxParameterSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
 	_type: PhantomData<T>,
 }
 
 #[derive(Clone)]
 pub struct ParamSetHandle {
 	inner: OfxParamSetHandle,
-	property: Rc<OfxPropertySuiteV1>,
-	parameter: Rc<OfxParameterSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
+	handle_cache: Option<Arc<HandleCache>>,
+}
+
+#[derive(Clone)]
+pub struct ParamSetDescriptorHandle {
+	inner: OfxParamSetHandle,
+	property: Arc<OfxPropertySuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
 }
 
+// clip and parameter handles stay valid for the lifetime of an instance, so
+// they are looked up once by name and shared by all the actions
+#[derive(Default)]
+pub(crate) struct HandleCache {
+	clips: Mutex<HashMap<CString, (OfxImageClipHandle, OfxPropertySetHandle)>>,
+	params: Mutex<HashMap<CString, (OfxParamHandle, OfxPropertySetHandle)>>,
+}
+
+// Suites are tables of function pointers, valid on any thread until the plugin
+// is unloaded, and handles are opaque host pointers. OFX allows property reads,
+// parameter value reads, clip image fetches and abort checks from the render
+// threads of an instance; calls that change an object are only legal in actions
+// the host does not run concurrently for that instance, such as describe.
+macro_rules! thread_safe_handle {
+	($($struct:ty),*) => {
+		$(unsafe impl Send for $struct {}
+		unsafe impl Sync for $struct {})
+		*
+	}
+}
+
+thread_safe_handle!(
+	PropertySetHandle,
+	GenericPluginHandle,
+	ImageEffectHost,
+	ImageEffectHandle,
+	ImageEffectDescriptorHandle,
+	ClipInstance,
+	Image,
+	ParamSetHandle,
+	ParamSetDescriptorHandle,
+	HandleCache
+);
+
+#[cfg(feature = "camera")]
+thread_safe_handle!(CameraInstance);
+
+unsafe impl<T> Send for ParamHandle<T> where T: ParamHandleValue + Send {}
+unsafe impl<T> Sync for ParamHandle<T> where T: ParamHandleValue + Sync {}
+
 // TODO: custom_derive?
 macro_rules! trivial_debug {
 	($($struct:ty),*) => {
@@ -118,24 +200,107 @@ macro_rules! trivial_debug {
 trivial_debug!(
 	ClipInstance,
 	ImageEffectHandle,
+	ImageEffectDescriptorHandle,
 	GenericPluginHandle,
 	ImageEffectHost
 );
 
+#[cfg(feature = "camera")]
+trivial_debug!(CameraInstance);
+
 impl ImageEffectHandle {
 	pub fn new(
 		inner: OfxImageEffectHandle,
-		property: Rc<OfxPropertySuiteV1>,
-		image_effect: Rc<OfxImageEffectSuiteV1>,
-		parameter: Rc<OfxParameterSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
+		image_effect: Arc<OfxImageEffectSuiteV1>,
+		parameter: Arc<OfxParameterSuiteV1>,
+		image_effect_plane: Option<ImageEffectPlaneSuite>,
 	) -> Self {
 		ImageEffectHandle {
 			inner,
 			property,
 			image_effect,
 			parameter,
+			image_effect_plane,
+			handle_cache: None,
+			#[cfg(feature = "camera")]
+			camera: None,
 		}
 	}
+
+	pub(crate) fn with_handle_cache(mut self, handle_cache: Option<Arc<HandleCache>>) -> Self {
+		self.handle_cache = handle_cache;
+		self
+	}
+
+	#[cfg(feature = "camera")]
+	pub fn with_camera(mut self, camera: Option<Arc<NukeOfxCameraSuiteV1>>) -> Self {
+		self.camera = camera;
+		self
+	}
+
+	// identifies the instance across the handles the host passes to each action
+	pub(crate) fn instance_key(&self) -> usize {
+		self.inner as usize
+	}
+}
+
+impl ImageEffectDescriptorHandle {
+	pub fn new(
+		inner: OfxImageEffectHandle,
+		property: Arc<OfxPropertySuiteV1>,
+		image_effect: Arc<OfxImageEffectSuiteV1>,
+		parameter: Arc<OfxParameterSuiteV1>,
+	) -> Self {
+		ImageEffectDescriptorHandle {
+			inner,
+			property,
+			image_effect,
+			parameter,
+			clip_names: Default::default(),
+			#[cfg(feature = "camera")]
+			camera: None,
+		}
+	}
+
+	#[cfg(feature = "camera")]
+	pub fn with_camera(mut self, camera: Option<Arc<NukeOfxCameraSuiteV1>>) -> Self {
+		self.camera = camera;
+		self
+	}
+
+	pub(crate) fn clip_names(&self) -> Result<Vec<CString>> {
+		Ok(self.clip_names.lock()?.clone())
+	}
+}
+
+impl HandleCache {
+	fn get_or_fetch<H, F>(handles: &Mutex<HashMap<CString, H>>, name: &CStr, fetch: F) -> Result<H>
+	where
+		H: Copy,
+		F: FnOnce() -> Result<H>,
+	{
+		if let Some(handle) = handles.lock()?.get(name) {
+			return Ok(*handle);
+		}
+		let handle = fetch()?;
+		handles.lock()?.insert(name.to_owned(), handle);
+		Ok(handle)
+	}
+
+	fn clip<F>(&self, name: &CStr, fetch: F) -> Result<(OfxImageClipHandle, OfxPropertySetHandle)>
+	where
+		F: FnOnce() -> Result<(OfxImageClipHandle, OfxPropertySetHandle)>,
+	{
+		Self::get_or_fetch(&self.clips, name, fetch)
+	}
+
+	fn param<F>(&self, name: &CStr, fetch: F) -> Result<(OfxParamHandle, OfxPropertySetHandle)>
+	where
+		F: FnOnce() -> Result<(OfxParamHandle, OfxPropertySetHandle)>,
+	{
+		Self::get_or_fetch(&self.params, name, fetch)
+	}
 }
 
 impl<T> ParamHandle<T>
@@ -145,8 +310,8 @@ where
 	pub fn new(
 		inner: OfxParamHandle,
 		inner_properties: OfxPropertySetHandle,
-		property: Rc<OfxPropertySuiteV1>,
-		parameter: Rc<OfxParameterSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
+		parameter: Arc<OfxParameterSuiteV1>,
 	) -> Self {
 		ParamHandle {
 			inner,
@@ -193,14 +358,16 @@ impl ClipInstance {
 	pub fn new(
 		inner: OfxImageClipHandle,
 		inner_properties: OfxPropertySetHandle,
-		property: Rc<OfxPropertySuiteV1>,
-		image_effect: Rc<OfxImageEffectSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
+		image_effect: Arc<OfxImageEffectSuiteV1>,
+		image_effect_plane: Option<ImageEffectPlaneSuite>,
 	) -> Self {
 		ClipInstance {
 			inner,
 			inner_properties,
 			property,
 			image_effect,
+			image_effect_plane,
 		}
 	}
 
@@ -215,22 +382,22 @@ impl ClipInstance {
 		Ok(value)
 	}
 
-	pub fn get_image_mut(&mut self, time: Time) -> Result<Rc<RefCell<Image>>> {
+	pub fn get_image_mut(&mut self, time: Time) -> Result<Arc<Mutex<Image>>> {
 		self.get_image_rect_mut(time, None)
 	}
 
-	pub fn get_image(&self, time: Time) -> Result<Rc<Image>> {
+	pub fn get_image(&self, time: Time) -> Result<Arc<Image>> {
 		self.get_image_rect(time, None)
 	}
 
-	pub fn get_image_rect(&self, time: Time, region: Option<RectD>) -> Result<Rc<Image>> {
+	pub fn get_image_rect(&self, time: Time, region: Option<RectD>) -> Result<Arc<Image>> {
 		let mut image: OfxPropertySetHandle = std::ptr::null_mut();
 		let region_ptr = region
 			.as_ref()
 			.map(|m| m as *const RectD)
 			.unwrap_or(std::ptr::null());
 		suite_fn!(clipGetImage in self.image_effect; self.inner, time, region_ptr, &mut image as *mut OfxPropertySetHandle)?;
-		Ok(Rc::new(Image::new(
+		Ok(Arc::new(Image::new(
 			image,
 			self.property.clone(),
 			self.image_effect.clone(),
@@ -241,19 +408,191 @@ impl ClipInstance {
 		&mut self,
 		time: Time,
 		region: Option<RectD>,
-	) -> Result<Rc<RefCell<Image>>> {
+	) -> Result<Arc<Mutex<Image>>> {
 		let mut image: OfxPropertySetHandle = std::ptr::null_mut();
 		let region_ptr = region
 			.as_ref()
 			.map(|m| m as *const RectD)
 			.unwrap_or(std::ptr::null());
 		suite_fn!(clipGetImage in self.image_effect; self.inner, time, region_ptr, &mut image as *mut OfxPropertySetHandle)?;
-		Ok(Rc::new(RefCell::new(Image::new(
+		Ok(Arc::new(Mutex::new(Image::new(
 			image,
 			self.property.clone(),
 			self.image_effect.clone(),
 		))))
 	}
+
+	pub fn get_planes_present(&self) -> Result<Vec<ImagePlane>> {
+		self.get_all::<ComponentsPresent::Property>()?
+			.iter()
+			.map(|plane| ImagePlane::from_cstring(plane).ok_or(Error::EnumNotFound))
+			.collect()
+	}
+
+	pub fn get_image_plane(&self, time: Time, plane: &ImagePlane) -> Result<Arc<Image>> {
+		self.get_image_plane_rect(time, plane, None)
+	}
+
+	pub fn get_image_plane_mut(
+		&mut self,
+		time: Time,
+		plane: &ImagePlane,
+	) -> Result<Arc<Mutex<Image>>> {
+		let image = self.fetch_image_plane(time, plane, None)?;
+		Ok(Arc::new(Mutex::new(Image::new(
+			image,
+			self.property.clone(),
+			self.image_effect.clone(),
+		))))
+	}
+
+	pub fn get_image_plane_rect(
+		&self,
+		time: Time,
+		plane: &ImagePlane,
+		region: Option<RectD>,
+	) -> Result<Arc<Image>> {
+		let image = self.fetch_image_plane(time, plane, region)?;
+		Ok(Arc::new(Image::new(
+			image,
+			self.property.clone(),
+			self.image_effect.clone(),
+		)))
+	}
+
+	// the colour plane can always be fetched through the standard suite,
+	// other planes need the multi-plane extension suite
+	fn fetch_image_plane(
+		&self,
+		time: Time,
+		plane: &ImagePlane,
+		region: Option<RectD>,
+	) -> Result<OfxPropertySetHandle> {
+		let mut image: OfxPropertySetHandle = std::ptr::null_mut();
+		let region_ptr = region
+			.as_ref()
+			.map(|m| m as *const RectD)
+			.unwrap_or(std::ptr::null());
+		match self.image_effect_plane {
+			Some(ImageEffectPlaneSuite::V2(ref image_effect_plane)) => {
+				const DEFAULT_VIEW: Int = 0;
+				let plane_name = plane.to_cstring();
+				suite_fn!(clipGetImagePlane in image_effect_plane;
+					self.inner, time, DEFAULT_VIEW, plane_name.as_ptr(), region_ptr, &mut image as *mut OfxPropertySetHandle)?;
+			}
+			Some(ImageEffectPlaneSuite::V1(ref image_effect_plane)) => {
+				let plane_name = plane.to_cstring();
+				suite_fn!(clipGetImagePlane in image_effect_plane;
+					self.inner, time, plane_name.as_ptr(), region_ptr, &mut image as *mut OfxPropertySetHandle)?;
+			}
+			None if plane.is_colour() => {
+				suite_fn!(clipGetImage in self.image_effect; self.inner, time, region_ptr, &mut image as *mut OfxPropertySetHandle)?;
+			}
+			None => return Err(Error::SuiteNotInitialized),
+		}
+		Ok(image)
+	}
+}
+
+#[cfg(feature = "camera")]
+impl CameraInstance {
+	pub fn new(
+		inner: NukeOfxCameraHandle,
+		inner_properties: OfxPropertySetHandle,
+		property: Arc<OfxPropertySuiteV1>,
+		camera: Arc<NukeOfxCameraSuiteV1>,
+	) -> Self {
+		CameraInstance {
+			inner,
+			inner_properties,
+			property,
+			camera,
+			view: 0,
+		}
+	}
+
+	// parameters are evaluated for view 0 unless specified,
+	// the current view is available from the action arguments
+	pub fn with_view(mut self, view: Int) -> Self {
+		self.view = view;
+		self
+	}
+
+	pub fn view(&self) -> Int {
+		self.view
+	}
+
+	pub fn get_parameter(&self, name: &[u8], time: Time, values: &mut [Double]) -> Result<()> {
+		suite_fn!(cameraGetParameter in self.camera;
+			self.inner, name.as_ptr() as *const i8, time, self.view, values.as_mut_ptr(), values.len() as Int)
+	}
+
+	fn get_double(&self, name: &[u8], time: Time) -> Result<Double> {
+		let mut value = [0.0];
+		self.get_parameter(name, time, &mut value)?;
+		Ok(value[0])
+	}
+
+	fn get_point(&self, name: &[u8], time: Time) -> Result<PointD> {
+		let mut value = [0.0; POINT_ELEMENTS as usize];
+		self.get_parameter(name, time, &mut value)?;
+		Ok(PointD {
+			x: value[0],
+			y: value[1],
+		})
+	}
+
+	pub fn get_projection_mode(&self, time: Time) -> Result<CameraProjectionMode> {
+		let value = self.get_double(kNukeOfxCameraParamProjectionMode, time)?;
+		CameraProjectionMode::from_double(value).ok_or(Error::EnumNotFound)
+	}
+
+	pub fn get_focal_length(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamFocalLength, time)
+	}
+
+	pub fn get_horizontal_aperture(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamHorizontalAperture, time)
+	}
+
+	pub fn get_vertical_aperture(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamVerticalAperture, time)
+	}
+
+	pub fn get_near(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamNear, time)
+	}
+
+	pub fn get_far(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamFar, time)
+	}
+
+	pub fn get_window_translate(&self, time: Time) -> Result<PointD> {
+		self.get_point(kNukeOfxCameraParamWindowTranslate, time)
+	}
+
+	pub fn get_window_scale(&self, time: Time) -> Result<PointD> {
+		self.get_point(kNukeOfxCameraParamWindowScale, time)
+	}
+
+	pub fn get_window_roll(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamWindowRoll, time)
+	}
+
+	pub fn get_focal_point(&self, time: Time) -> Result<Double> {
+		self.get_double(kNukeOfxCameraParamFocalPoint, time)
+	}
+
+	// homogeneous 4x4 transform placing the camera in world space,
+	// carries both position and orientation
+	pub fn get_position_matrix(
+		&self,
+		time: Time,
+	) -> Result<[Double; CAMERA_MATRIX_ELEMENTS as usize]> {
+		let mut value = [0.0; CAMERA_MATRIX_ELEMENTS as usize];
+		self.get_parameter(kNukeOfxCameraParamPositionMatrix, time, &mut value)?;
+		Ok(value)
+	}
 }
 
 impl Drop for Image {
@@ -266,8 +605,8 @@ impl Drop for Image {
 impl Image {
 	pub fn new(
 		inner: OfxPropertySetHandle,
-		property: Rc<OfxPropertySuiteV1>,
-		image_effect: Rc<OfxImageEffectSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
+		image_effect: Arc<OfxImageEffectSuiteV1>,
 	) -> Self {
 		Image {
 			inner,
@@ -276,6 +615,17 @@ impl Image {
 		}
 	}
 
+	/// Pixel coordinates in this image, at its render scale, pixel aspect ratio and field
+	pub fn canonical_to_pixels(&self, rect: RectD) -> Result<RectI> {
+		let render_scale = field_render_scale(self.get_render_scale()?, self.get_field()?);
+		Ok(rect.to_pixels(render_scale, self.get_pixel_aspect_ratio()?))
+	}
+
+	pub fn pixels_to_canonical(&self, rect: RectI) -> Result<RectD> {
+		let render_scale = field_render_scale(self.get_render_scale()?, self.get_field()?);
+		Ok(rect.to_canonical(render_scale, self.get_pixel_aspect_ratio()?))
+	}
+
 	pub fn get_descriptor<T>(&self) -> Result<ImageDescriptor<T>>
 	where
 		T: PixelFormat,
@@ -287,7 +637,7 @@ impl Image {
 		Ok(ImageDescriptor::new(bounds, row_bytes, ptr))
 	}
 
-	fn get_descriptor_mut<T>(&mut self) -> Result<ImageDescriptorMut<T>>
+	pub fn get_descriptor_mut<T>(&mut self) -> Result<ImageDescriptorMut<T>>
 	where
 		T: PixelFormat,
 	{
@@ -315,51 +665,77 @@ impl Image {
 	}
 }
 
-impl HasProperties<ClipDescriptor> for ClipInstance {
-	fn properties(&self) -> Result<ClipDescriptor> {
-		Ok(ClipDescriptor::new(
-			self.inner_properties,
-			self.property.clone(),
-		))
-	}
-}
-
 trait IsPropertiesNewType {
 	fn wrap(inner: PropertySetHandle) -> Self;
 }
 
 pub trait PropertiesNewTypeConstructor {
-	fn build(host: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> Self;
+	fn build(host: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> Self;
 }
 
 #[inline]
-pub fn build_typed<T>(host: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> T
+pub fn build_typed<T>(host: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> T
 where
 	T: PropertiesNewTypeConstructor,
 {
 	T::build(host, property)
 }
 
+// without the describe time clip names every clip is accepted
+fn check_known_clip(clip_names: Option<&[CString]>, clip_name: &CStr) -> Result<()> {
+	let is_known = match clip_names {
+		Some(clip_names) => clip_names.iter().any(|known| known.as_c_str() == clip_name),
+		None => true,
+	};
+	if is_known {
+		Ok(())
+	} else {
+		warn!("Clip {:?} was not defined at describe time", clip_name);
+		Err(Error::UnknownClip)
+	}
+}
+
 macro_rules! properties_newtype {
 	($name:ident) => {
 		#[derive(Clone)]
 		pub struct $name(PropertySetHandle);
 
+		properties_newtype!(@impl $name());
+	};
+	// out args keyed by clip name, checked against the clips defined at describe time
+	($name:ident with clip names) => {
+		#[derive(Clone)]
+		pub struct $name(PropertySetHandle, Option<Arc<Vec<CString>>>);
+
+		properties_newtype!(@impl $name(, None));
+
+		impl $name {
+			pub(crate) fn with_clip_names(mut self, clip_names: Option<Arc<Vec<CString>>>) -> Self {
+				self.1 = clip_names;
+				self
+			}
+
+			fn check_clip_name(&self, clip_name: &CStr) -> Result<()> {
+				check_known_clip(self.1.as_ref().map(|clip_names| &clip_names[..]), clip_name)
+			}
+		}
+	};
+	(@impl $name:ident($($clip_names:tt)*)) => {
 		impl IsPropertiesNewType for $name {
 			fn wrap(inner: PropertySetHandle) -> Self {
-				$name(inner)
+				$name(inner $($clip_names)*)
 			}
 		}
 
 		impl PropertiesNewTypeConstructor for $name {
-			fn build(host: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> Self {
+			fn build(host: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> Self {
 				$name::new(host, property)
 			}
 		}
 
 		impl $name {
-			pub fn new(host: OfxPropertySetHandle, property: Rc<OfxPropertySuiteV1>) -> Self {
-				$name(PropertySetHandle::new(host, property))
+			pub fn new(host: OfxPropertySetHandle, property: Arc<OfxPropertySuiteV1>) -> Self {
+				$name(PropertySetHandle::new(host, property) $($clip_names)*)
 			}
 		}
 
@@ -380,6 +756,8 @@ properties_newtype!(HostProperties);
 properties_newtype!(EffectDescriptor);
 properties_newtype!(EffectInstance);
 properties_newtype!(ClipDescriptor);
+#[cfg(feature = "camera")]
+properties_newtype!(CameraDescriptor);
 
 properties_newtype!(DescribeInContextInArgs);
 
@@ -387,9 +765,9 @@ properties_newtype!(GetRegionOfDefinitionInArgs);
 properties_newtype!(GetRegionOfDefinitionOutArgs);
 
 properties_newtype!(GetRegionsOfInterestInArgs);
-properties_newtype!(GetRegionsOfInterestOutArgs);
+properties_newtype!(GetRegionsOfInterestOutArgs with clip names);
 
-properties_newtype!(GetClipPreferencesOutArgs);
+properties_newtype!(GetClipPreferencesOutArgs with clip names);
 
 properties_newtype!(IsIdentityInArgs);
 properties_newtype!(IsIdentityOutArgs);
@@ -408,16 +786,137 @@ properties_newtype!(BeginSequenceRenderInArgs);
 properties_newtype!(RenderInArgs);
 properties_newtype!(EndSequenceRenderInArgs);
 
+properties_newtype!(GetClipComponentsInArgs);
+properties_newtype!(GetClipComponentsOutArgs);
+
 properties_newtype!(ParamDouble);
 properties_newtype!(ParamInt);
 properties_newtype!(ParamBoolean);
+properties_newtype!(ParamChoice);
+// ParamString is taken by the string mode enum
+properties_newtype!(ParamText);
+properties_newtype!(ParamCustom);
+properties_newtype!(ParamPushButton);
 properties_newtype!(ParamPage);
 properties_newtype!(ParamGroup);
 
 properties_newtype!(ParameterSet);
+properties_newtype!(ParameterSetInstance);
 
 impl DescribeInContextInArgs {}
 
+impl RenderInArgs {
+	pub fn get_render_planes(&self) -> Result<Vec<ImagePlane>> {
+		self.get_all::<RenderPlanes::Property>()?
+			.iter()
+			.map(|plane| ImagePlane::from_cstring(plane).ok_or(Error::EnumNotFound))
+			.collect()
+	}
+}
+
+impl GetRegionsOfInterestInArgs {
+	/// The requested region grown by a kernel radius in canonical coordinates
+	pub fn expand_region_of_interest(&self, radius: PointD) -> Result<RectD> {
+		Ok(self.get_region_of_interest()?.expand(radius))
+	}
+
+	/// The requested region grown by a kernel radius in pixels at the render scale
+	pub fn expand_region_of_interest_by_pixels(
+		&self,
+		radius: PointD,
+		pixel_aspect_ratio: Double,
+	) -> Result<RectD> {
+		let render_scale = self.get_render_scale()?;
+		Ok(self.get_region_of_interest()?.expand_by_pixels(
+			radius,
+			render_scale,
+			pixel_aspect_ratio,
+		))
+	}
+}
+
+impl GetRegionsOfInterestOutArgs {
+	/// Takes the clip instance, rather than its name, to check it is connected
+	pub fn set_roi(&mut self, clip: &ClipInstance, roi: RectD) -> Result<()> {
+		let clip_name = CString::new(clip.get_name()?)?;
+		self.check_clip_name(&clip_name)?;
+		if !clip.get_connected()? {
+			return Err(Error::ClipNotConnected);
+		}
+		let property_name = format!("{}{}", image_clip_prop_roi!(""), clip_name.to_str()?);
+		self.set_raw(&property_name[..], &roi)
+	}
+}
+
+impl GetClipPreferencesOutArgs {
+	pub fn clip<N>(&mut self, clip_name: N) -> Result<ClipPreferences>
+	where
+		N: StringId,
+	{
+		let clip_name = clip_name.c_name()?.into_owned();
+		self.check_clip_name(&clip_name)?;
+		Ok(ClipPreferences {
+			out_args: self,
+			clip_name,
+		})
+	}
+
+	pub fn set_fielding(&mut self, fielding: ImageFieldOrder) -> Result<()> {
+		self.set_field_order(fielding)
+	}
+}
+
+/// The preferences of a single clip, written by GetClipPreferences
+pub struct ClipPreferences<'a> {
+	out_args: &'a mut GetClipPreferencesOutArgs,
+	clip_name: CString,
+}
+
+impl<'a> ClipPreferences<'a> {
+	pub fn set_components<C>(&mut self, components: C) -> Result<()>
+	where
+		C: Into<ImageComponents>,
+	{
+		let property_name = self.property_name(image_clip_prop_components!(""))?;
+		let components: ImageComponents = components.into();
+		self.out_args.set_raw(
+			&property_name[..],
+			components.to_cstring().as_bytes_with_nul(),
+		)
+	}
+
+	pub fn set_depth(&mut self, depth: BitDepth) -> Result<()> {
+		let property_name = self.property_name(image_clip_prop_depth!(""))?;
+		self.out_args.set_raw(&property_name[..], depth.to_bytes())
+	}
+
+	pub fn set_pixel_aspect_ratio(&mut self, pixel_aspect_ratio: Double) -> Result<()> {
+		let property_name = self.property_name(image_clip_prop_par!(""))?;
+		self.out_args
+			.set_raw(&property_name[..], &pixel_aspect_ratio)
+	}
+
+	fn property_name(&self, prefix: &str) -> Result<String> {
+		Ok(format!("{}{}", prefix, self.clip_name.to_str()?))
+	}
+}
+
+impl GetClipComponentsOutArgs {
+	pub fn set_clip_planes(&mut self, clip_name: &str, planes: &[ImagePlane]) -> Result<()> {
+		let property_name = format!(
+			"{}{}",
+			static_bytes_to_string(kFnOfxImageEffectActionGetClipComponentsPropString),
+			clip_name
+		);
+		// the out args may already hold planes, a shorter list must not keep them
+		self.reset_raw(&property_name[..])?;
+		for (index, plane) in planes.iter().enumerate() {
+			self.set_raw_at(&property_name[..], index, &plane.to_string()[..])?;
+		}
+		Ok(())
+	}
+}
+
 impl HasProperties<EffectInstance> for ImageEffectHandle {
 	fn properties(&self) -> Result<EffectInstance> {
 		let property_set_handle = {
@@ -434,7 +933,7 @@ impl HasProperties<EffectInstance> for ImageEffectHandle {
 	}
 }
 
-impl HasProperties<EffectDescriptor> for ImageEffectHandle {
+impl HasProperties<EffectDescriptor> for ImageEffectDescriptorHandle {
 	fn properties(&self) -> Result<EffectDescriptor> {
 		let property_set_handle = {
 			let mut property_set_handle = std::ptr::null_mut();
@@ -452,35 +951,34 @@ impl HasProperties<EffectDescriptor> for ImageEffectHandle {
 
 
 impl ImageEffectHandle {
-	fn clip_define(&self, clip_name: &[u8]) -> Result<ClipDescriptor> {
-		let property_set_handle = {
-			let mut property_set_handle = std::ptr::null_mut();
-			suite_fn!(clipDefine in self.image_effect;
-				self.inner, clip_name.as_ptr() as *const i8, &mut property_set_handle as *mut _)?;
-			property_set_handle
-		};
-		Ok(ClipDescriptor(PropertySetHandle::new(
-			property_set_handle,
-			self.property.clone(),
-		)))
-	}
-
-	fn clip_get_handle(&self, clip_name: &[u8]) -> Result<ClipInstance> {
-		let (clip_handle, clip_properties) = {
+	fn clip_get_handle(&self, clip_name: &CStr) -> Result<ClipInstance> {
+		let fetch = || -> Result<(OfxImageClipHandle, OfxPropertySetHandle)> {
 			let mut clip_handle = std::ptr::null_mut();
 			let mut clip_properties = std::ptr::null_mut();
 			suite_fn!(clipGetHandle in self.image_effect;
-				self.inner, clip_name.as_ptr() as *const i8, &mut clip_handle as *mut _, &mut clip_properties as *mut _)?;
-			(clip_handle, clip_properties)
+				self.inner, clip_name.as_ptr(), &mut clip_handle as *mut _, &mut clip_properties as *mut _)?;
+			Ok((clip_handle, clip_properties))
+		};
+		let (clip_handle, clip_properties) = match self.handle_cache {
+			Some(ref handle_cache) => handle_cache.clip(clip_name, fetch)?,
+			None => fetch()?,
 		};
 		Ok(ClipInstance::new(
 			clip_handle,
 			clip_properties,
 			self.property.clone(),
 			self.image_effect.clone(),
+			self.image_effect_plane.clone(),
 		))
 	}
 
+	pub fn new_image_memory<T>(&self, bounds: RectI) -> Result<ImageMemory<T>>
+	where
+		T: PixelFormat,
+	{
+		ImageMemory::new(self.inner, self.image_effect.clone(), bounds)
+	}
+
 	pub fn abort(&self) -> Result<Bool> {
 		Ok(suite_call!(abort in self.image_effect; self.inner) != 0)
 	}
@@ -495,33 +993,23 @@ impl ImageEffectHandle {
 			parameters_set_handle,
 			self.parameter.clone(),
 			self.property.clone(),
-		))
+		)
+		.with_handle_cache(self.handle_cache.clone()))
 	}
 
 	pub fn get_output_clip(&self) -> Result<ClipInstance> {
-		self.clip_get_handle(ofx_sys::kOfxImageEffectOutputClipName)
+		self.get_clip(&ofx_sys::kOfxImageEffectOutputClipName[..])
 	}
 
 	pub fn get_simple_input_clip(&self) -> Result<ClipInstance> {
-		self.clip_get_handle(ofx_sys::kOfxImageEffectSimpleSourceClipName)
+		self.get_clip(&ofx_sys::kOfxImageEffectSimpleSourceClipName[..])
 	}
 
-	pub fn get_clip(&self, name: &str) -> Result<ClipInstance> {
-		let str_buf = CString::new(name)?.into_bytes_with_nul();
-		self.clip_get_handle(&str_buf)
-	}
-
-	pub fn new_output_clip(&self) -> Result<ClipDescriptor> {
-		self.clip_define(ofx_sys::kOfxImageEffectOutputClipName)
-	}
-
-	pub fn new_simple_input_clip(&self) -> Result<ClipDescriptor> {
-		self.clip_define(ofx_sys::kOfxImageEffectSimpleSourceClipName)
-	}
-
-	pub fn new_clip(&self, name: &str) -> Result<ClipDescriptor> {
-		let str_buf = CString::new(name)?.into_bytes_with_nul();
-		self.clip_define(&str_buf)
+	pub fn get_clip<N>(&self, name: N) -> Result<ClipInstance>
+	where
+		N: StringId,
+	{
+		self.clip_get_handle(&name.c_name()?)
 	}
 
 	unsafe fn get_pointer(&self) -> Result<*mut [u8]> {
@@ -535,8 +1023,7 @@ impl ImageEffectHandle {
 		let mut effect_props: EffectInstance = self.properties()?;
 		let data_box = Box::new(data);
 		let data_ptr = Box::into_raw(data_box);
-		let status = suite_fn!(propSetPointer in self.property;
-			effect_props.0.inner, kOfxPropInstanceData.as_ptr() as *const i8, 0, data_ptr as *mut _);
+		let status = effect_props.set_instance_data_ptr(data_ptr as VoidPtr);
 		if status.is_err() {
 			unsafe {
 				Box::from_raw(data_ptr);
@@ -546,22 +1033,19 @@ impl ImageEffectHandle {
 	}
 
 	fn get_instance_data_ptr(&self) -> Result<VoidPtrMut> {
-		let mut effect_props: EffectInstance = self.properties()?;
-		let mut data_ptr = std::ptr::null_mut();
-		to_result! { suite_call!(propGetPointer in self.property;
-		   effect_props.0.inner, kOfxPropInstanceData.as_ptr() as *const i8, 0, &mut data_ptr)
-		=> data_ptr }
+		let effect_props: EffectInstance = self.properties()?;
+		effect_props.get_instance_data_ptr()
 	}
 
-	// TODO: this is not safe enough
-	pub fn get_instance_data<T>(&self) -> Result<&mut T>
+	/// Shared, as the host can run actions of one instance on several threads,
+	/// mutable state needs atomics or a HostMutex
+	pub fn get_instance_data<T>(&self) -> Result<&T>
 	where
-		T: Sized,
+		T: Sized + Sync,
 	{
 		unsafe {
-			let mut ptr = self.get_instance_data_ptr()?;
-			let mut reference = ptr as *mut T;
-			Ok(&mut *reference)
+			let ptr = self.get_instance_data_ptr()?;
+			Ok(&*(ptr as *const T))
 		}
 	}
 
@@ -576,24 +1060,191 @@ impl ImageEffectHandle {
 	}
 }
 
+impl ImageEffectDescriptorHandle {
+	fn clip_define(&self, clip_name: &CStr) -> Result<ClipDescriptor> {
+		let property_set_handle = {
+			let mut property_set_handle = std::ptr::null_mut();
+			suite_fn!(clipDefine in self.image_effect;
+				self.inner, clip_name.as_ptr(), &mut property_set_handle as *mut _)?;
+			property_set_handle
+		};
+		self.clip_names.lock()?.push(clip_name.to_owned());
+		Ok(ClipDescriptor(PropertySetHandle::new(
+			property_set_handle,
+			self.property.clone(),
+		)))
+	}
+
+	pub fn parameter_set(&self) -> Result<ParamSetDescriptorHandle> {
+		let parameters_set_handle = {
+			let mut parameters_set_handle = std::ptr::null_mut();
+			suite_fn!(getParamSet in self.image_effect; self.inner, &mut parameters_set_handle as *mut _)?;
+			parameters_set_handle
+		};
+		Ok(ParamSetDescriptorHandle::new(
+			parameters_set_handle,
+			self.parameter.clone(),
+			self.property.clone(),
+		))
+	}
+
+	pub fn new_output_clip(&self) -> Result<ClipDescriptor> {
+		self.new_clip(&ofx_sys::kOfxImageEffectOutputClipName[..])
+	}
+
+	pub fn new_simple_input_clip(&self) -> Result<ClipDescriptor> {
+		self.new_clip(&ofx_sys::kOfxImageEffectSimpleSourceClipName[..])
+	}
+
+	pub fn new_clip<N>(&self, name: N) -> Result<ClipDescriptor>
+	where
+		N: StringId,
+	{
+		self.clip_define(&name.c_name()?)
+	}
+}
+
+#[cfg(feature = "camera")]
+impl ImageEffectDescriptorHandle {
+	fn camera_define(&self, camera_name: &CStr) -> Result<CameraDescriptor> {
+		let camera = self.camera.clone().ok_or(Error::SuiteNotInitialized)?;
+		let property_set_handle = {
+			let mut property_set_handle = std::ptr::null_mut();
+			suite_fn!(cameraDefine in camera;
+				self.inner, camera_name.as_ptr(), &mut property_set_handle as *mut _)?;
+			property_set_handle
+		};
+		Ok(CameraDescriptor(PropertySetHandle::new(
+			property_set_handle,
+			self.property.clone(),
+		)))
+	}
+
+	pub fn supports_cameras(&self) -> bool {
+		self.camera.is_some()
+	}
+
+	pub fn new_camera<N>(&self, name: N) -> Result<CameraDescriptor>
+	where
+		N: StringId,
+	{
+		self.camera_define(&name.c_name()?)
+	}
+}
+
+#[cfg(feature = "camera")]
+impl ImageEffectHandle {
+	fn camera_suite(&self) -> Result<Arc<NukeOfxCameraSuiteV1>> {
+		self.camera.clone().ok_or(Error::SuiteNotInitialized)
+	}
+
+	fn camera_get_handle(&self, camera_name: &CStr) -> Result<CameraInstance> {
+		let camera = self.camera_suite()?;
+		let (camera_handle, camera_properties) = {
+			let mut camera_handle = std::ptr::null_mut();
+			let mut camera_properties = std::ptr::null_mut();
+			suite_fn!(cameraGetHandle in camera;
+				self.inner, camera_name.as_ptr(), &mut camera_handle as *mut _, &mut camera_properties as *mut _)?;
+			(camera_handle, camera_properties)
+		};
+		Ok(CameraInstance::new(
+			camera_handle,
+			camera_properties,
+			self.property.clone(),
+			camera,
+		))
+	}
+
+	pub fn supports_cameras(&self) -> bool {
+		self.camera.is_some()
+	}
+
+	pub fn get_camera<N>(&self, name: N) -> Result<CameraInstance>
+	where
+		N: StringId,
+	{
+		self.camera_get_handle(&name.c_name()?)
+	}
+}
+
 impl ParamSetHandle {
 	pub fn new(
 		inner: OfxParamSetHandle,
-		parameter: Rc<OfxParameterSuiteV1>,
-		property: Rc<OfxPropertySuiteV1>,
+		parameter: Arc<OfxParameterSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
 	) -> Self {
 		ParamSetHandle {
 			inner,
 			parameter,
 			property,
+			handle_cache: None,
 		}
 	}
 
-	fn param_define<T>(&mut self, param_type: ParamType, name: &str) -> Result<T>
+	pub(crate) fn with_handle_cache(mut self, handle_cache: Option<Arc<HandleCache>>) -> Self {
+		self.handle_cache = handle_cache;
+		self
+	}
+
+	pub fn parameter<T, N>(&self, name: N) -> Result<ParamHandle<T>>
+	where
+		T: ParamHandleValue,
+		N: StringId,
+	{
+		let name_buf = name.c_name()?;
+		let fetch = || -> Result<(OfxParamHandle, OfxPropertySetHandle)> {
+			let mut param_handle = std::ptr::null_mut();
+			let mut param_properties = std::ptr::null_mut();
+			suite_fn!(paramGetHandle in self.parameter;
+				self.inner, name_buf.as_ptr(), &mut param_handle as *mut _, &mut param_properties as *mut _)?;
+			Ok((param_handle, param_properties))
+		};
+		let (param_handle, param_properties) = match self.handle_cache {
+			Some(ref handle_cache) => handle_cache.param(&name_buf, fetch)?,
+			None => fetch()?,
+		};
+		Ok(ParamHandle::new(
+			param_handle,
+			param_properties,
+			self.property.clone(),
+			self.parameter.clone(),
+		))
+	}
+}
+
+impl HasProperties<ParameterSetInstance> for ParamSetHandle {
+	fn properties(&self) -> Result<ParameterSetInstance> {
+		let property_set_handle = {
+			let mut property_set_handle = std::ptr::null_mut();
+			suite_fn!(paramSetGetPropertySet in self.parameter; self.inner, &mut property_set_handle as *mut _)?;
+			property_set_handle
+		};
+		Ok(ParameterSetInstance(PropertySetHandle::new(
+			property_set_handle,
+			self.property.clone(),
+		)))
+	}
+}
+
+impl ParamSetDescriptorHandle {
+	pub fn new(
+		inner: OfxParamSetHandle,
+		parameter: Arc<OfxParameterSuiteV1>,
+		property: Arc<OfxPropertySuiteV1>,
+	) -> Self {
+		ParamSetDescriptorHandle {
+			inner,
+			parameter,
+			property,
+		}
+	}
+
+	fn param_define<T, N>(&mut self, param_type: ParamType, name: N) -> Result<T>
 	where
 		T: IsPropertiesNewType,
+		N: StringId,
 	{
-		let name_buf = CString::new(name)?.into_bytes_with_nul();
+		let name_buf = name.c_name()?;
 		let property_set_handle = {
 			let mut property_set_handle = std::ptr::null_mut();
 			suite_fn!(paramDefine in self.parameter;
@@ -607,47 +1258,84 @@ impl ParamSetHandle {
 		)))
 	}
 
-	pub fn parameter<T>(&self, name: &str) -> Result<ParamHandle<T>>
+	pub fn param_define_double<N>(&mut self, name: N) -> Result<ParamDouble>
 	where
-		T: ParamHandleValue,
+		N: StringId,
 	{
-		let name_buf = CString::new(name)?.into_bytes_with_nul();
-		let (param_handle, param_properties) = {
-			let mut param_handle = std::ptr::null_mut();
-			let mut param_properties = std::ptr::null_mut();
-			suite_fn!(paramGetHandle in self.parameter;
-				self.inner, name_buf.as_ptr() as *const _, &mut param_handle as *mut _, &mut param_properties as *mut _)?;
-			(param_handle, param_properties)
-		};
-		Ok(ParamHandle::new(
-			param_handle,
-			param_properties,
-			self.property.clone(),
-			self.parameter.clone(),
-		))
-	}
-
-	pub fn param_define_double(&mut self, name: &str) -> Result<ParamDouble> {
 		self.param_define(ParamType::Double, name)
 	}
 
-	pub fn param_define_int(&mut self, name: &str) -> Result<ParamInt> {
+	pub fn param_define_int<N>(&mut self, name: N) -> Result<ParamInt>
+	where
+		N: StringId,
+	{
 		self.param_define(ParamType::Integer, name)
 	}
 
-	pub fn param_define_boolean(&mut self, name: &str) -> Result<ParamBoolean> {
+	pub fn param_define_boolean<N>(&mut self, name: N) -> Result<ParamBoolean>
+	where
+		N: StringId,
+	{
 		self.param_define(ParamType::Boolean, name)
 	}
 
-	pub fn param_define_group(&mut self, name: &str) -> Result<ParamGroup> {
+	pub fn param_define_choice<N>(&mut self, name: N) -> Result<ParamChoice>
+	where
+		N: StringId,
+	{
+		self.param_define(ParamType::Choice, name)
+	}
+
+	pub fn param_define_string<N>(&mut self, name: N) -> Result<ParamText>
+	where
+		N: StringId,
+	{
+		self.param_define(ParamType::String, name)
+	}
+
+	pub fn param_define_custom<N>(&mut self, name: N) -> Result<ParamCustom>
+	where
+		N: StringId,
+	{
+		self.param_define(ParamType::Custom, name)
+	}
+
+	pub fn param_define_push_button<N>(&mut self, name: N) -> Result<ParamPushButton>
+	where
+		N: StringId,
+	{
+		self.param_define(ParamType::PushButton, name)
+	}
+
+	pub fn param_define_group<N>(&mut self, name: N) -> Result<ParamGroup>
+	where
+		N: StringId,
+	{
 		self.param_define(ParamType::Group, name)
 	}
 
-	pub fn param_define_page(&mut self, name: &str) -> Result<ParamPage> {
+	pub fn param_define_page<N>(&mut self, name: N) -> Result<ParamPage>
+	where
+		N: StringId,
+	{
 		self.param_define(ParamType::Page, name)
 	}
 }
 
+impl HasProperties<ParameterSet> for ParamSetDescriptorHandle {
+	fn properties(&self) -> Result<ParameterSet> {
+		let property_set_handle = {
+			let mut property_set_handle = std::ptr::null_mut();
+			suite_fn!(paramSetGetPropertySet in self.parameter; self.inner, &mut property_set_handle as *mut _)?;
+			property_set_handle
+		};
+		Ok(ParameterSet(PropertySetHandle::new(
+			property_set_handle,
+			self.property.clone(),
+		)))
+	}
+}
+
 impl AsProperties for ImageEffectHost {
 	fn handle(&self) -> OfxPropertySetHandle {
 		self.inner
@@ -666,6 +1354,16 @@ impl AsProperties for ClipInstance {
 	}
 }
 
+#[cfg(feature = "camera")]
+impl AsProperties for CameraInstance {
+	fn handle(&self) -> OfxPropertySetHandle {
+		self.inner_properties
+	}
+	fn suite(&self) -> *const OfxPropertySuiteV1 {
+		self.property.borrow() as *const _
+	}
+}
+
 impl AsProperties for Image {
 	fn handle(&self) -> OfxPropertySetHandle {
 		self.inner
@@ -699,4 +1397,20 @@ mod tests {
 		handle.get::<property::Type::Property>();
 		handle.get::<property::IsBackground::Property>();
 	}
+
+	#[test]
+	fn unknown_clips_are_rejected() {
+		let clip_names = vec![
+			CString::new("Source").unwrap(),
+			CString::new("Output").unwrap(),
+		];
+		let source = CString::new("Source").unwrap();
+		let mask = CString::new("Mask").unwrap();
+		assert!(check_known_clip(Some(&clip_names), &source).is_ok());
+		assert!(match check_known_clip(Some(&clip_names), &mask) {
+			Err(Error::UnknownClip) => true,
+			_ => false,
+		});
+		assert!(check_known_clip(None, &mask).is_ok());
+	}
 }
@@ -605,6 +605,10 @@ where
 		self.data.row(y)
 	}
 
+	pub fn bounds(&self) -> RectI {
+		self.data.bounds()
+	}
+
 	pub fn row_range(&self, x1: Int, x2: Int, y: Int) -> &[T] {
 		let slice = self.row(y);
 		&slice[(x1 - self.data.bounds.x1) as usize..(x2 - self.data.bounds.x1) as usize]
@@ -633,6 +637,24 @@ where
 		&mut slice[x1..x2]
 	}
 
+	pub fn bounds(&self) -> RectI {
+		self.data.bounds()
+	}
+
+	/// Splits the image in disjoint bands of rows, with tile rows in image coordinates
+	pub fn into_bands(self, n_bands: usize) -> Vec<ImageTileMut<'a, T>> {
+		let (_, height) = self.data.dimensions();
+		let n_bands = n_bands.max(1);
+		let rows_per_band = ((height as usize + n_bands - 1) / n_bands).max(1);
+		self.data
+			.chunks_mut(rows_per_band)
+			.map(|band| {
+				let bounds = band.bounds();
+				ImageTileMut::new(bounds.y1, bounds.y2, band)
+			})
+			.collect()
+	}
+
 	pub fn into_tiles(self, n_chunks: usize) -> Vec<ImageTileMut<'a, T>> {
 		let (width, height) = self.data.dimensions();
 		let rows_per_chunk = height as usize / n_chunks;
@@ -656,6 +678,10 @@ where
 		ImageTileMut { y1, y2, data }
 	}
 
+	pub fn bounds(&self) -> RectI {
+		self.data.bounds()
+	}
+
 	pub fn row(&mut self, y: Int) -> &mut [T] {
 		self.data.row_mut(y)
 	}
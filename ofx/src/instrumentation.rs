@@ -0,0 +1,183 @@
+use enums::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use types::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionStats {
+	pub calls: u64,
+	pub failures: u64,
+	pub total: Duration,
+	pub max: Duration,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+	pub renders: u64,
+	pub pixels: u64,
+	pub max_window_pixels: u64,
+	pub pixel_depths: Vec<(BitDepth, u64)>,
+	pub host_threads: u32,
+	pub max_concurrent_renders: usize,
+}
+
+/// Per-action call counts and durations, and render statistics, of one plugin
+#[derive(Default)]
+pub struct Instrumentation {
+	actions: Mutex<HashMap<String, ActionStats>>,
+	render: Mutex<RenderStats>,
+	renders_in_flight: AtomicUsize,
+}
+
+impl ActionStats {
+	pub fn mean(&self) -> Duration {
+		if self.calls == 0 {
+			Duration::default()
+		} else {
+			Duration::from_secs_f64(self.total.as_secs_f64() / self.calls as f64)
+		}
+	}
+}
+
+impl Instrumentation {
+	pub fn new() -> Self {
+		Instrumentation::default()
+	}
+
+	pub fn record_action(&self, action: &str, duration: Duration, failed: bool) {
+		if let Ok(mut actions) = self.actions.lock() {
+			let stats = actions.entry(action.to_owned()).or_default();
+			stats.calls += 1;
+			if failed {
+				stats.failures += 1;
+			}
+			stats.total += duration;
+			stats.max = stats.max.max(duration);
+		}
+	}
+
+	pub fn begin_render(&self, window: RectI, pixel_depth: Option<BitDepth>, host_threads: u32) {
+		let in_flight = self.renders_in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+		let pixels = (window.x2 - window.x1).max(0) as u64 * (window.y2 - window.y1).max(0) as u64;
+		if let Ok(mut render) = self.render.lock() {
+			render.renders += 1;
+			render.pixels += pixels;
+			render.max_window_pixels = render.max_window_pixels.max(pixels);
+			render.host_threads = render.host_threads.max(host_threads);
+			render.max_concurrent_renders = render.max_concurrent_renders.max(in_flight);
+			if let Some(pixel_depth) = pixel_depth {
+				match render
+					.pixel_depths
+					.iter_mut()
+					.find(|(depth, _)| *depth == pixel_depth)
+				{
+					Some((_, count)) => *count += 1,
+					None => render.pixel_depths.push((pixel_depth, 1)),
+				}
+			}
+		}
+	}
+
+	pub fn end_render(&self) {
+		self.renders_in_flight.fetch_sub(1, Ordering::SeqCst);
+	}
+
+	/// Action statistics sorted by total time, slowest first
+	pub fn actions(&self) -> Vec<(String, ActionStats)> {
+		let mut actions: Vec<(String, ActionStats)> = self
+			.actions
+			.lock()
+			.map(|actions| {
+				actions
+					.iter()
+					.map(|(name, stats)| (name.clone(), stats.clone()))
+					.collect()
+			})
+			.unwrap_or_default();
+		actions.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+		actions
+	}
+
+	pub fn render(&self) -> RenderStats {
+		self.render
+			.lock()
+			.map(|render| render.clone())
+			.unwrap_or_default()
+	}
+}
+
+impl fmt::Display for ActionStats {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"calls:{} failures:{} total:{:?} mean:{:?} max:{:?}",
+			self.calls,
+			self.failures,
+			self.total,
+			self.mean(),
+			self.max
+		)
+	}
+}
+
+impl fmt::Display for RenderStats {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"renders:{} pixels:{} max_window_pixels:{} depths:{:?} host_threads:{} max_concurrent:{}",
+			self.renders,
+			self.pixels,
+			self.max_window_pixels,
+			self.pixel_depths,
+			self.host_threads,
+			self.max_concurrent_renders
+		)
+	}
+}
+
+impl fmt::Display for Instrumentation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (name, stats) in self.actions() {
+			writeln!(f, "{}: {}", name, stats)?;
+		}
+		write!(f, "{}", self.render())
+	}
+}
+
+mod tests {
+	use super::*;
+
+	#[test]
+	fn record_actions_and_renders() {
+		let instrumentation = Instrumentation::new();
+		instrumentation.record_action("OfxActionLoad", Duration::from_millis(1), false);
+		instrumentation.record_action(
+			"OfxImageEffectActionRender",
+			Duration::from_millis(5),
+			false,
+		);
+		instrumentation.record_action("OfxImageEffectActionRender", Duration::from_millis(3), true);
+		let actions = instrumentation.actions();
+		assert!(actions[0].0 == "OfxImageEffectActionRender");
+		assert!(actions[0].1.calls == 2 && actions[0].1.failures == 1);
+		assert!(actions[0].1.max == Duration::from_millis(5));
+
+		let window = RectI {
+			x1: 0,
+			y1: 0,
+			x2: 10,
+			y2: 20,
+		};
+		instrumentation.begin_render(window, Some(BitDepth::Float), 8);
+		instrumentation.begin_render(window, Some(BitDepth::Float), 8);
+		instrumentation.end_render();
+		instrumentation.end_render();
+		let render = instrumentation.render();
+		assert!(render.pixels == 400);
+		assert!(render.max_concurrent_renders == 2);
+		assert!(render.pixel_depths == vec![(BitDepth::Float, 2)]);
+	}
+}
@@ -0,0 +1,437 @@
+use ofx_sys::*;
+use property::*;
+use result::*;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use types::*;
+use util::static_bytes_to_string;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PropertyType {
+	Int,
+	Double,
+	String,
+	Pointer,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyDescription {
+	pub name: String,
+	pub property_type: PropertyType,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+	Int(Int),
+	Double(Double),
+	String(String),
+	Pointer(usize),
+}
+
+/// A snapshot of a single property, dimension is None when the property is not set
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyDump {
+	pub name: String,
+	pub property_type: PropertyType,
+	pub dimension: Option<usize>,
+	pub values: Vec<PropertyValue>,
+}
+
+pub trait TypedValue {
+	fn property_type() -> PropertyType;
+}
+
+macro_rules! typed_value {
+	($property_type:ident => $($value_type:ty),*) => {
+		$(impl TypedValue for $value_type {
+			fn property_type() -> PropertyType {
+				PropertyType::$property_type
+			}
+		})
+		*
+	};
+}
+
+typed_value!(Int => Bool, Int, PointI, RangeI, RectI);
+typed_value!(Double => Double, PointD, RangeD, RectD);
+typed_value!(String => String, CString, str, [u8], CharPtr);
+typed_value!(Pointer => VoidPtr, VoidPtrMut);
+
+impl PropertyDescription {
+	pub fn of<P>() -> Self
+	where
+		P: Named + Get,
+		P::ReturnType: TypedValue,
+	{
+		PropertyDescription {
+			name: static_bytes_to_string(P::name()),
+			property_type: <P::ReturnType as TypedValue>::property_type(),
+		}
+	}
+}
+
+/// Lists the properties known for an object type, as declared in its object_properties! table
+pub trait Introspect: Readable {
+	fn known_properties() -> Vec<PropertyDescription>;
+
+	fn dump(&self) -> Vec<PropertyDump> {
+		dump_properties(self, &Self::known_properties())
+	}
+
+	fn dump_to_string(&self) -> String {
+		let lines: Vec<String> = self.dump().iter().map(|p| p.to_string()).collect();
+		lines.join("\n")
+	}
+}
+
+pub fn dump_properties<R>(readable: &R, descriptions: &[PropertyDescription]) -> Vec<PropertyDump>
+where
+	R: Readable,
+{
+	descriptions
+		.iter()
+		.map(|description| dump_property(readable, description))
+		.collect()
+}
+
+pub fn dump_property<R>(readable: &R, description: &PropertyDescription) -> PropertyDump
+where
+	R: Readable,
+{
+	let values = CString::new(description.name.clone())
+		.map_err(Error::from)
+		.and_then(|c_name| read_values(readable, &c_name, description.property_type));
+	let (dimension, values) = match values {
+		Ok(values) => (Some(values.len()), values),
+		Err(_) => (None, Vec::new()),
+	};
+	PropertyDump {
+		name: description.name.clone(),
+		property_type: description.property_type,
+		dimension,
+		values,
+	}
+}
+
+fn read_values<R>(
+	readable: &R,
+	c_name: &CStr,
+	property_type: PropertyType,
+) -> Result<Vec<PropertyValue>>
+where
+	R: Readable,
+{
+	let c_name = c_name.as_ptr();
+	let mut dimension: Int = 0;
+	suite_fn!(propGetDimension in *readable.suite(); readable.handle(), c_name, &mut dimension as *mut Int)?;
+	(0..dimension as usize)
+		.map(|index| match property_type {
+			PropertyType::Int => {
+				<Int as RawGetter<R>>::get_at(readable, c_name, index).map(PropertyValue::Int)
+			}
+			PropertyType::Double => {
+				<Double as RawGetter<R>>::get_at(readable, c_name, index).map(PropertyValue::Double)
+			}
+			PropertyType::String => {
+				<String as RawGetter<R>>::get_at(readable, c_name, index).map(PropertyValue::String)
+			}
+			PropertyType::Pointer => <VoidPtrMut as RawGetter<R>>::get_at(readable, c_name, index)
+				.map(|value| PropertyValue::Pointer(value as usize)),
+		})
+		.collect()
+}
+
+pub(crate) fn unique_properties(properties: Vec<PropertyDescription>) -> Vec<PropertyDescription> {
+	let mut unique: Vec<PropertyDescription> = Vec::new();
+	for property in properties {
+		if !unique.iter().any(|known| known.name == property.name) {
+			unique.push(property);
+		}
+	}
+	unique
+}
+
+impl fmt::Display for PropertyType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let name = match *self {
+			PropertyType::Int => "int",
+			PropertyType::Double => "double",
+			PropertyType::String => "string",
+			PropertyType::Pointer => "pointer",
+		};
+		write!(f, "{}", name)
+	}
+}
+
+impl fmt::Display for PropertyValue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			PropertyValue::Int(value) => write!(f, "{}", value),
+			PropertyValue::Double(value) => write!(f, "{}", value),
+			PropertyValue::String(ref value) => write!(f, "{:?}", value),
+			PropertyValue::Pointer(value) => write!(f, "0x{:x}", value),
+		}
+	}
+}
+
+impl fmt::Display for PropertyDump {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.dimension {
+			Some(dimension) => {
+				let values: Vec<String> = self.values.iter().map(|v| v.to_string()).collect();
+				write!(
+					f,
+					"{}: {} x {} = [{}]",
+					self.name,
+					self.property_type,
+					dimension,
+					values.join(", ")
+				)
+			}
+			None => write!(f, "{}: {} (not set)", self.name, self.property_type),
+		}
+	}
+}
+
+mod tests {
+	use super::*;
+	use handle::*;
+
+	#[test]
+	fn clip_descriptor_known_properties() {
+		let properties = ClipDescriptor::known_properties();
+		assert!(properties.contains(&PropertyDescription {
+			name: "OfxPropLabel".to_owned(),
+			property_type: PropertyType::String,
+		}));
+		assert!(properties.contains(&PropertyDescription {
+			name: "OfxImageClipPropOptional".to_owned(),
+			property_type: PropertyType::Int,
+		}));
+	}
+
+	#[test]
+	fn effect_descriptor_properties_are_unique() {
+		let properties = EffectDescriptor::known_properties();
+		let labels = properties
+			.iter()
+			.filter(|property| property.name == "OfxPropLabel")
+			.count();
+		assert!(labels == 1);
+	}
+
+	// property names with no object type in this crate, grouped by reason
+	const UNMAPPED_PROPERTIES: &[&str] = &[
+		// not property names
+		"kOfxPropertySuite",
+		"kFnOfxImageEffectActionGetClipComponentsPropString",
+		"kNatronOfxParamPropDoubleTypeMatrix3x3",
+		"kNatronOfxParamPropTypeRectangle",
+		"kOfxImageEffectPropRenderQualityPreview",
+		"kOfxImageEffectPropRenderQualityGood",
+		"kOfxImageEffectPropRenderQualityBest",
+		"kOfxImageEffectPropVegasContextUnknown",
+		"kOfxImageEffectPropVegasContextMedia",
+		"kOfxImageEffectPropVegasContextTrack",
+		"kOfxImageEffectPropVegasContextEvent",
+		"kOfxImageEffectPropVegasContextEventFadeIn",
+		"kOfxImageEffectPropVegasContextEventFadeOut",
+		"kOfxImageEffectPropVegasContextProject",
+		"kOfxImageEffectPropVegasContextGenerator",
+		// interacts are not wrapped
+		"kOfxPropEffectInstance",
+		"kOfxPropKeySym",
+		"kOfxPropKeyString",
+		"kOfxInteractPropSlaveToParam",
+		"kOfxInteractPropPixelScale",
+		"kOfxInteractPropBackgroundColour",
+		"kOfxInteractPropSuggestedColour",
+		"kOfxInteractPropPenPosition",
+		"kOfxInteractPropPenViewportPosition",
+		"kOfxInteractPropPenPressure",
+		"kOfxInteractPropBitDepth",
+		"kOfxInteractPropHasAlpha",
+		"kOfxInteractPropViewportSize",
+		"kOfxInteractPropScreenPixelRatio",
+		"kOfxImageEffectPluginPropHWndInteractV1",
+		"kOfxHWndInteractPropParent",
+		"kOfxHWndInteractPropMinSize",
+		"kOfxHWndInteractPropPreferredSize",
+		"kOfxHWndInteractPropLocation",
+		// custom interpolation callback arguments
+		"kOfxParamPropCustomValue",
+		"kOfxParamPropInterpolationTime",
+		"kOfxParamPropInterpolationAmount",
+		// multidimensional and parametric parameters are not wrapped
+		"kOfxParamPropDimensionLabel",
+		"kOfxParamPropParametricDimension",
+		"kOfxParamPropParametricUIColour",
+		"kOfxParamPropParametricInteractBackground",
+		"kOfxParamPropParametricRange",
+		// transform and OpenGL render actions are not wrapped
+		"kFnOfxPropMatrix2D",
+		"kOfxOpenGLPropPixelDepth",
+		"kOfxImageEffectPropOpenGLEnabled",
+		"kOfxImageEffectPropOpenGLTextureIndex",
+		"kOfxImageEffectPropOpenGLTextureTarget",
+		// Natron extensions
+		"kNatronOfxParamHostPropSupportsDynamicChoices",
+		"kNatronOfxParamPropChoiceCascading",
+		"kNatronOfxParamPropChoiceHostCanAddOptions",
+		"kNatronOfxImageEffectPropChannelSelector",
+		"kNatronOfxImageEffectPropHostMasking",
+		"kNatronOfxImageEffectPropHostMixing",
+		"kNatronOfxParamPropIsInstanceSpecific",
+		"kNatronOfxImageEffectPropDeprecated",
+		"kNatronOfxImageEffectPropProjectId",
+		"kNatronOfxImageEffectPropGroupId",
+		"kNatronOfxImageEffectPropInstanceId",
+		"kNatronOfxPropNativeOverlays",
+		"kNatronOfxPropDescriptionIsMarkdown",
+		"kNatronOfxGroupParamPropIsDialog",
+		"kNatronOfxImageEffectPropDefaultCursors",
+		"kNatronOfxImageEffectPropInViewerContextParamsOrder",
+		"kNatronOfxParamPropInViewerContextLayoutHint",
+		"kNatronOfxParamPropInViewerContextLayoutPadWidth",
+		"kNatronOfxParamPropInViewerContextLabel",
+		"kNatronOfxParamPropInViewerContextSecret",
+		"kNatronOfxBooleanParamPropIsToggableButton",
+		"kNatronOfxParamPropInViewerContextIsInToolbar",
+		"kNatronOfxParamPropInViewerContextCanHaveShortcut",
+		"kNatronOfxImageEffectPropInViewerContextDefaultShortcuts",
+		"kNatronOfxImageEffectPropInViewerContextShortcutSymbol",
+		"kNatronOfxImageEffectPropInViewerContextShortcutHasControlModifier",
+		"kNatronOfxImageEffectPropInViewerContextShortcutHasShiftModifier",
+		"kNatronOfxImageEffectPropInViewerContextShortcutHasAltModifier",
+		"kNatronOfxImageEffectPropInViewerContextShortcutHasMetaModifier",
+		"kNatronOfxImageEffectPropInViewerContextShortcutHasKeypadModifier",
+		"kNatronOfxImageEffectPropOpenGLContextData",
+		"kNatronOfxPropPickerColour",
+		"kNatronOfxParamPropParametricIsPeriodic",
+		"kOfxImageClipPropFormat",
+		"kOfxImageEffectPropCanDistort",
+		"kOfxPropInverseDistortionFunction",
+		"kOfxPropInverseDistortionFunctionData",
+		"kOfxPropInverseDistortionFunctionDataSize",
+		"kOfxPropInverseDistortionDataFreeFunction",
+		"kOfxPropMatrix3x3",
+		// Vegas extensions
+		"kOfxPropVegasHostAppDataDirectory",
+		"kOfxParamPropColorWheelLevel",
+		"kOfxParamPropParameterExpanded",
+		"kOfxImageEffectPropHelpFile",
+		"kOfxImageEffectPropHelpContextID",
+		"kOfxImageEffectPropVegasUpliftGUID",
+		"kOfxPropVegasUpliftKeyframeData",
+		"kOfxPropVegasUpliftKeyframeDataLength",
+		"kOfxPropVegasUpliftKeyframeTime",
+		"kOfxPropVegasUpliftKeyframeInterpolation",
+		"kOfxPropVegasUpliftData",
+		"kOfxPropVegasUpliftDataLength",
+		"kOfxImagePropPixelOrder",
+		"kOfxImageEffectPropViewsToRender",
+		"kOfxImageEffectPropRenderView",
+		"kOfxImageEffectPropRenderQuality",
+		"kOfxImageEffectPropVegasContext",
+	];
+
+	// the string constants with Prop in their name, as (constant, value without nul)
+	fn property_names(bindings: &str) -> Vec<(&str, &str)> {
+		bindings
+			.split("pub const ")
+			.skip(1)
+			.filter_map(|declaration| {
+				let mut parts = declaration.splitn(2, ':');
+				let constant = parts.next()?;
+				let rest = parts.next()?.trim_start();
+				if !constant.contains("Prop") || !rest.starts_with("&'static [u8;") {
+					return None;
+				}
+				let value = rest.splitn(2, "b\"").nth(1)?.split("\\0\"").next()?;
+				Some((constant, value))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn every_property_is_known_or_unmapped() {
+		let mut bindings = vec![include_str!("../../ofx-sys/src/bindings.rs")];
+		#[cfg(feature = "natron")]
+		bindings.push(include_str!("../../ofx-sys/src/bindings_natron.rs"));
+		#[cfg(feature = "vegas")]
+		bindings.push(include_str!("../../ofx-sys/src/bindings_vegas.rs"));
+		#[cfg(feature = "opengl")]
+		bindings.push(include_str!("../../ofx-sys/src/bindings_opengl.rs"));
+		#[cfg(feature = "parametric")]
+		bindings.push(include_str!("../../ofx-sys/src/bindings_parametric.rs"));
+		#[cfg(feature = "camera")]
+		bindings.push(include_str!("../../ofx-sys/src/bindings_camera.rs"));
+
+		let mut known = vec![
+			ImageEffectHost::known_properties(),
+			EffectDescriptor::known_properties(),
+			EffectInstance::known_properties(),
+			ClipDescriptor::known_properties(),
+			ClipInstance::known_properties(),
+			Image::known_properties(),
+			ParamDouble::known_properties(),
+			ParamInt::known_properties(),
+			ParamBoolean::known_properties(),
+			ParamChoice::known_properties(),
+			ParamText::known_properties(),
+			ParamCustom::known_properties(),
+			ParamPushButton::known_properties(),
+			ParamPage::known_properties(),
+			ParamGroup::known_properties(),
+			ParameterSet::known_properties(),
+			ParameterSetInstance::known_properties(),
+			ParamHandle::<Double>::known_properties(),
+			ParamHandle::<Int>::known_properties(),
+			ParamHandle::<Bool>::known_properties(),
+			DescribeInContextInArgs::known_properties(),
+			IsIdentityInArgs::known_properties(),
+			IsIdentityOutArgs::known_properties(),
+			GetRegionOfDefinitionInArgs::known_properties(),
+			GetRegionOfDefinitionOutArgs::known_properties(),
+			GetRegionsOfInterestInArgs::known_properties(),
+			GetRegionsOfInterestOutArgs::known_properties(),
+			GetClipPreferencesOutArgs::known_properties(),
+			InstanceChangedInArgs::known_properties(),
+			BeginInstanceChangedInArgs::known_properties(),
+			EndInstanceChangedInArgs::known_properties(),
+			RenderInArgs::known_properties(),
+			BeginSequenceRenderInArgs::known_properties(),
+			EndSequenceRenderInArgs::known_properties(),
+			GetTimeDomainOutArgs::known_properties(),
+			GetClipComponentsInArgs::known_properties(),
+			GetClipComponentsOutArgs::known_properties(),
+		];
+		#[cfg(feature = "camera")]
+		known.extend(vec![
+			CameraDescriptor::known_properties(),
+			CameraInstance::known_properties(),
+		]);
+		let known: Vec<String> = known
+			.into_iter()
+			.flat_map(|properties| properties.into_iter().map(|property| property.name))
+			.collect();
+
+		for (constant, value) in bindings
+			.iter()
+			.flat_map(|bindings| property_names(bindings))
+		{
+			let is_known = known.iter().any(|name| name == value);
+			let is_unmapped = UNMAPPED_PROPERTIES.contains(&constant);
+			assert!(
+				is_known || is_unmapped,
+				"{} is not known to any object",
+				constant
+			);
+			assert!(
+				!(is_known && is_unmapped),
+				"{} is known, it is not unmapped",
+				constant
+			);
+		}
+	}
+}
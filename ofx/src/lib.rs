@@ -5,7 +5,11 @@
 extern crate ofx_sys;
 #[macro_use]
 extern crate log;
+#[cfg(feature = "log4rs")]
 extern crate log4rs;
+extern crate phf;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
@@ -21,21 +25,42 @@ mod suites;
 #[macro_use]
 mod util;
 mod action;
+mod capabilities;
+mod coordinates;
 mod enums;
 mod handle;
+#[cfg(feature = "instrumentation")]
+mod instrumentation;
+mod introspect;
+mod logging;
+mod memory;
+mod name;
+mod plane;
 mod plugin;
 mod property;
+mod thread;
 mod types;
 #[macro_use]
 mod registry;
 mod image;
 pub use action::*;
+pub use capabilities::*;
+pub use coordinates::*;
 pub use enums::*;
 pub use enums::Type as Type;
 pub use handle::*;
+#[cfg(feature = "instrumentation")]
+pub use instrumentation::*;
+pub use introspect::*;
+pub use logging::*;
+pub use memory::*;
+pub use name::*;
+pub use plane::*;
 pub use plugin::*;
 pub use property::*;
 pub use result::*;
+pub use suites::*;
+pub use thread::*;
 pub use types::*;
 pub use util::*;
 pub use image::*;
@@ -0,0 +1,110 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use ofx_sys::*;
+use result::*;
+use std::env;
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::sync::Arc;
+use types::*;
+
+/// Path of a log4rs configuration file, read when the plugin library is first loaded.
+/// Library messages about a plugin are logged with the plugin module as target,
+/// so each plugin can be configured as its own logger.
+pub const LOG_CONFIG_ENV: &str = "OFX_LOG4RS_CONFIG";
+
+/// Read from the working directory when LOG_CONFIG_ENV is not set
+pub const DEFAULT_LOG_CONFIG: &str = "ofx_log4rs.yaml";
+
+// another library in the host process may have installed a logger already,
+// in which case ours is skipped
+#[cfg(feature = "log4rs")]
+pub(crate) fn init_logging() {
+	use log4rs::append::console::*;
+	use log4rs::config::*;
+
+	let config_error = match env::var(LOG_CONFIG_ENV) {
+		Ok(config_path) => match log4rs::init_file(&config_path, Default::default()) {
+			Ok(()) => return,
+			Err(e) => Some(format!("{}: {}", config_path, e)),
+		},
+		// the default file is optional, so failing to read it is not reported
+		Err(_) => match log4rs::init_file(DEFAULT_LOG_CONFIG, Default::default()) {
+			Ok(()) => return,
+			Err(_) => None,
+		},
+	};
+	let config = Config::builder()
+		.appender(Appender::builder().build(
+			"stdout".to_string(),
+			Box::new(ConsoleAppender::builder().build()),
+		))
+		.logger(Logger::builder().build("ofx".to_string(), LevelFilter::Warn))
+		.build(
+			Root::builder()
+				.appender("stdout".to_string())
+				.build(LevelFilter::Error),
+		);
+	if let Ok(config) = config {
+		if log4rs::init_config(config).is_ok() {
+			if let Some(config_error) = config_error {
+				warn!("Unable to configure logging from {}", config_error);
+			}
+		}
+	}
+}
+
+#[cfg(not(feature = "log4rs"))]
+pub(crate) fn init_logging() {}
+
+/// Posts log records through the host message suite, so they are visible to the user.
+/// Errors and warnings keep their severity, anything else is posted as a log message.
+pub struct HostMessageLogger {
+	message: Arc<OfxMessageSuiteV1>,
+	level: LevelFilter,
+}
+
+impl HostMessageLogger {
+	pub fn new(message: Arc<OfxMessageSuiteV1>, level: LevelFilter) -> Self {
+		HostMessageLogger { message, level }
+	}
+
+	/// Installs as the process logger, fails if a logger is already installed
+	pub fn install(self) -> Result<()> {
+		let level = self.level;
+		log::set_logger(Box::leak(Box::new(self))).map_err(|_| Error::LoggerAlreadySet)?;
+		log::set_max_level(level);
+		Ok(())
+	}
+
+	fn post(&self, message_type: &[u8], text: &CStr) -> Result<()> {
+		suite_fn!(message in self.message;
+			ptr::null_mut(),
+			message_type.as_ptr() as CharPtr,
+			ptr::null(),
+			b"%s\0".as_ptr() as CharPtr,
+			text.as_ptr())
+	}
+}
+
+impl Log for HostMessageLogger {
+	fn enabled(&self, metadata: &Metadata) -> bool {
+		metadata.level() <= self.level
+	}
+
+	fn log(&self, record: &Record) {
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+		let message_type: &[u8] = match record.level() {
+			Level::Error => kOfxMessageError,
+			Level::Warn => kOfxMessageWarning,
+			_ => kOfxMessageLog,
+		};
+		// a message with an interior nul can't be passed to the host, drop it
+		if let Ok(text) = CString::new(format!("{}: {}", record.target(), record.args())) {
+			self.post(message_type, &text).ok();
+		}
+	}
+
+	fn flush(&self) {}
+}
@@ -0,0 +1,339 @@
+use image::*;
+use ofx_sys::*;
+use result::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::ptr::NonNull;
+use std::slice;
+use std::sync::Arc;
+use types::*;
+
+// the memory suite takes no alignment and the spec promises none, only trust
+// it with the alignment of a double, the system allocator handles the rest
+const HOST_ALIGNMENT: usize = 8;
+
+/// Allocates from the host memory suite, so the host can account for
+/// and purge plugin memory, falls back to the system allocator otherwise
+#[derive(Clone)]
+pub struct HostAllocator {
+	memory: Option<Arc<OfxMemorySuiteV1>>,
+	handle: VoidPtrMut,
+}
+
+// the memory suite is thread safe and the handle is only passed through to it
+unsafe impl Send for HostAllocator {}
+unsafe impl Sync for HostAllocator {}
+
+impl HostAllocator {
+	pub fn new(memory: Option<Arc<OfxMemorySuiteV1>>, handle: VoidPtrMut) -> Self {
+		HostAllocator { memory, handle }
+	}
+
+	pub fn system() -> Self {
+		HostAllocator::new(None, ptr::null_mut())
+	}
+
+	pub fn is_host(&self) -> bool {
+		match self.memory {
+			Some(ref memory) => memory.memoryAlloc.is_some() && memory.memoryFree.is_some(),
+			None => false,
+		}
+	}
+
+	// decided on the layout only, so that dealloc always matches alloc
+	fn uses_host(&self, layout: Layout) -> bool {
+		self.is_host() && layout.align() <= HOST_ALIGNMENT
+	}
+}
+
+unsafe impl GlobalAlloc for HostAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		if !self.uses_host(layout) {
+			return System.alloc(layout);
+		}
+		let mut data: VoidPtrMut = ptr::null_mut();
+		let status = match self.memory.as_ref().and_then(|memory| memory.memoryAlloc) {
+			Some(memory_alloc) => memory_alloc(self.handle, layout.size() as size_t, &mut data),
+			None => eOfxStatus_Failed,
+		};
+		if status == eOfxStatus_OK {
+			data as *mut u8
+		} else {
+			error!(
+				"Host failed to allocate {} bytes: {}",
+				layout.size(),
+				status
+			);
+			ptr::null_mut()
+		}
+	}
+
+	unsafe fn dealloc(&self, data: *mut u8, layout: Layout) {
+		if !self.uses_host(layout) {
+			return System.dealloc(data, layout);
+		}
+		if let Some(memory_free) = self.memory.as_ref().and_then(|memory| memory.memoryFree) {
+			memory_free(data as VoidPtrMut);
+		}
+	}
+}
+
+/// A growable buffer of T owned by a HostAllocator
+pub struct HostVec<T> {
+	data: NonNull<T>,
+	len: usize,
+	capacity: usize,
+	allocator: HostAllocator,
+	_type: PhantomData<T>,
+}
+
+// owns its elements like a Vec, and the allocator is thread safe
+unsafe impl<T> Send for HostVec<T> where T: Send {}
+unsafe impl<T> Sync for HostVec<T> where T: Sync {}
+
+impl<T> HostVec<T> {
+	pub fn new(allocator: HostAllocator) -> Self {
+		HostVec {
+			data: NonNull::dangling(),
+			len: 0,
+			capacity: 0,
+			allocator,
+			_type: PhantomData,
+		}
+	}
+
+	pub fn with_capacity(allocator: HostAllocator, capacity: usize) -> Result<Self> {
+		let mut host_vec = HostVec::new(allocator);
+		host_vec.reserve(capacity)?;
+		Ok(host_vec)
+	}
+
+	pub fn from_elem(allocator: HostAllocator, value: T, len: usize) -> Result<Self>
+	where
+		T: Clone,
+	{
+		let mut host_vec = HostVec::with_capacity(allocator, len)?;
+		for _ in 0..len {
+			host_vec.push(value.clone())?;
+		}
+		Ok(host_vec)
+	}
+
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	pub fn allocator(&self) -> &HostAllocator {
+		&self.allocator
+	}
+
+	pub fn reserve(&mut self, additional: usize) -> Result<()> {
+		let required = self
+			.len
+			.checked_add(additional)
+			.ok_or(Error::InvalidValue)?;
+		if required <= self.capacity || mem::size_of::<T>() == 0 {
+			return Ok(());
+		}
+		let capacity = match self.capacity.checked_mul(2) {
+			Some(doubled) => required.max(doubled),
+			None => required,
+		};
+		let layout = Layout::array::<T>(capacity).map_err(|_| Error::InvalidValue)?;
+		unsafe {
+			let data = self.allocator.alloc(layout) as *mut T;
+			let data = NonNull::new(data).ok_or(Error::OutOfMemory)?;
+			ptr::copy_nonoverlapping(self.data.as_ptr(), data.as_ptr(), self.len);
+			self.release();
+			self.data = data;
+		}
+		self.capacity = capacity;
+		Ok(())
+	}
+
+	pub fn push(&mut self, value: T) -> Result<()> {
+		self.reserve(1)?;
+		unsafe {
+			ptr::write(self.data.as_ptr().add(self.len), value);
+		}
+		self.len += 1;
+		Ok(())
+	}
+
+	pub fn pop(&mut self) -> Option<T> {
+		if self.len == 0 {
+			None
+		} else {
+			self.len -= 1;
+			unsafe { Some(ptr::read(self.data.as_ptr().add(self.len))) }
+		}
+	}
+
+	pub fn clear(&mut self) {
+		while self.pop().is_some() {}
+	}
+
+	// frees the buffer without dropping the elements
+	unsafe fn release(&mut self) {
+		if self.capacity > 0 && mem::size_of::<T>() > 0 {
+			let layout = Layout::array::<T>(self.capacity).expect("Layout was valid on allocation");
+			self.allocator
+				.dealloc(self.data.as_ptr() as *mut u8, layout);
+		}
+	}
+}
+
+impl<T> Deref for HostVec<T> {
+	type Target = [T];
+	fn deref(&self) -> &[T] {
+		unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len) }
+	}
+}
+
+impl<T> DerefMut for HostVec<T> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+	}
+}
+
+impl<T> Drop for HostVec<T> {
+	fn drop(&mut self) {
+		self.clear();
+		unsafe {
+			self.release();
+		}
+	}
+}
+
+/// Host managed image buffer, the host may move or page it out while unlocked
+pub struct ImageMemory<T>
+where
+	T: PixelFormat,
+{
+	inner: OfxImageMemoryHandle,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
+	bounds: RectI,
+	row_bytes: Int,
+	_type: PhantomData<T>,
+}
+
+pub struct ImageMemoryLock<'a, T>
+where
+	T: PixelFormat + 'a,
+{
+	memory: &'a mut ImageMemory<T>,
+	data: VoidPtrMut,
+}
+
+// the memory handle is owned, locking it requires a mutable reference
+unsafe impl<T> Send for ImageMemory<T> where T: PixelFormat + Send {}
+unsafe impl<T> Sync for ImageMemory<T> where T: PixelFormat + Sync {}
+
+impl<T> ImageMemory<T>
+where
+	T: PixelFormat,
+{
+	pub(crate) fn new(
+		effect: OfxImageEffectHandle,
+		image_effect: Arc<OfxImageEffectSuiteV1>,
+		bounds: RectI,
+	) -> Result<Self> {
+		let width = (bounds.x2 - bounds.x1).max(0) as usize;
+		let height = (bounds.y2 - bounds.y1).max(0) as usize;
+		let row_bytes = width
+			.checked_mul(mem::size_of::<T>())
+			.ok_or(Error::InvalidValue)?;
+		let bytes = row_bytes.checked_mul(height).ok_or(Error::InvalidValue)?;
+		let mut inner: OfxImageMemoryHandle = ptr::null_mut();
+		suite_fn!(imageMemoryAlloc in image_effect; effect, bytes as size_t, &mut inner as *mut _)?;
+		Ok(ImageMemory {
+			inner,
+			image_effect,
+			bounds,
+			row_bytes: row_bytes as Int,
+			_type: PhantomData,
+		})
+	}
+
+	pub fn bounds(&self) -> RectI {
+		self.bounds
+	}
+
+	pub fn row_bytes(&self) -> Int {
+		self.row_bytes
+	}
+
+	pub fn lock(&mut self) -> Result<ImageMemoryLock<T>> {
+		let mut data: VoidPtrMut = ptr::null_mut();
+		suite_fn!(imageMemoryLock in self.image_effect; self.inner, &mut data as *mut _)?;
+		Ok(ImageMemoryLock { memory: self, data })
+	}
+
+	fn unlock(&mut self) -> Result<()> {
+		suite_fn!(imageMemoryUnlock in self.image_effect; self.inner)
+	}
+
+	fn free(&mut self) -> Result<()> {
+		suite_fn!(imageMemoryFree in self.image_effect; self.inner)
+	}
+}
+
+impl<T> Drop for ImageMemory<T>
+where
+	T: PixelFormat,
+{
+	fn drop(&mut self) {
+		if self.free().is_err() {
+			error!("Unable to free image memory {:?}", self.inner);
+		}
+	}
+}
+
+impl<'a, T> ImageMemoryLock<'a, T>
+where
+	T: PixelFormat,
+{
+	pub fn descriptor(&self) -> ImageDescriptor<T> {
+		ImageDescriptor::new(self.memory.bounds, self.memory.row_bytes, self.data)
+	}
+
+	pub fn descriptor_mut(&mut self) -> ImageDescriptorMut<T> {
+		ImageDescriptorMut::new(self.memory.bounds, self.memory.row_bytes, self.data)
+	}
+}
+
+impl<'a, T> Drop for ImageMemoryLock<'a, T>
+where
+	T: PixelFormat,
+{
+	fn drop(&mut self) {
+		if self.memory.unlock().is_err() {
+			error!("Unable to unlock image memory {:?}", self.memory.inner);
+		}
+	}
+}
+
+mod tests {
+	use super::*;
+
+	#[test]
+	fn host_vec_system_fallback() {
+		let mut buffer = HostVec::from_elem(HostAllocator::system(), 1.0f32, 10).unwrap();
+		buffer.push(2.0).unwrap();
+		assert!(buffer.len() == 11);
+		assert!(buffer[10] == 2.0);
+		buffer[0] = 3.0;
+		assert!(buffer.iter().sum::<f32>() == 14.0);
+	}
+}
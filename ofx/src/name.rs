@@ -0,0 +1,97 @@
+use result::*;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+use types::*;
+
+/// A nul terminated name which lives as long as the plugin, so it can be
+/// handed to the host any number of times without allocating
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OfxName(&'static CStr);
+
+impl OfxName {
+	pub const fn new(name: &'static CStr) -> Self {
+		OfxName(name)
+	}
+
+	/// Panics if the name is not nul terminated, use ofx_name! for literals
+	pub const fn from_literal(name: &'static str) -> Self {
+		match CStr::from_bytes_with_nul(name.as_bytes()) {
+			Ok(name) => OfxName(name),
+			Err(_) => panic!("OfxName literals must end with a single nul"),
+		}
+	}
+
+	pub fn from_bytes_with_nul(name: &'static [u8]) -> Result<Self> {
+		CStr::from_bytes_with_nul(name)
+			.map(OfxName)
+			.map_err(|_| Error::InvalidNameEncoding)
+	}
+
+	/// Equal names share the same storage, which is allocated only once
+	pub fn intern(name: &str) -> Result<Self> {
+		static INTERNED_NAMES: OnceLock<Mutex<HashMap<&'static str, &'static CStr>>> =
+			OnceLock::new();
+		let mut interned_names = INTERNED_NAMES.get_or_init(Default::default).lock()?;
+		if let Some(interned) = interned_names.get(name) {
+			return Ok(OfxName(interned));
+		}
+		let interned: &'static CStr = Box::leak(CString::new(name)?.into_boxed_c_str());
+		interned_names.insert(interned.to_str()?, interned);
+		Ok(OfxName(interned))
+	}
+
+	pub fn as_c_str(self) -> &'static CStr {
+		self.0
+	}
+
+	pub fn as_ptr(self) -> CharPtr {
+		self.0.as_ptr()
+	}
+
+	pub fn to_bytes(self) -> &'static [u8] {
+		self.0.to_bytes()
+	}
+}
+
+impl fmt::Debug for OfxName {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:?}", self.0)
+	}
+}
+
+impl fmt::Display for OfxName {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.0.to_string_lossy())
+	}
+}
+
+/// Builds an OfxName constant from a string literal
+#[macro_export]
+macro_rules! ofx_name {
+	($name:expr) => {
+		$crate::OfxName::from_literal(concat!($name, "\0"))
+	};
+}
+
+mod tests {
+	use super::*;
+
+	const SCALE: OfxName = ofx_name!("scale");
+
+	#[test]
+	fn literal_names() {
+		assert!(SCALE.to_bytes() == b"scale");
+		assert!(OfxName::from_bytes_with_nul(b"scale\0").ok() == Some(SCALE));
+		assert!(OfxName::from_bytes_with_nul(b"scale").is_err());
+	}
+
+	#[test]
+	fn interned_names() {
+		let first = OfxName::intern("scaleR").unwrap();
+		let second = OfxName::intern(&String::from("scaleR")).unwrap();
+		assert!(first.as_ptr() == second.as_ptr());
+		assert!(first.to_string() == "scaleR");
+	}
+}
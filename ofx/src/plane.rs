@@ -0,0 +1,352 @@
+use enums::{IdentifiedEnum, ImageComponent};
+use ofx_sys::*;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use util::static_bytes_to_string;
+
+/// A custom layer as described by the Natron multi-plane extension, encoded as
+/// plane name, optional labels and the list of channel names.
+#[cfg(feature = "natron")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlaneComponents {
+	pub name: String,
+	pub label: Option<String>,
+	pub channels_label: Option<String>,
+	pub channels: Vec<String>,
+}
+
+/// Components of a clip or image: either one of the standard OFX/Natron/Nuke
+/// component types, a Natron custom plane, or any other string a host may send.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImageComponents {
+	Standard(ImageComponent),
+	#[cfg(feature = "natron")]
+	Plane(PlaneComponents),
+	Other(String),
+}
+
+/// An image plane which can be fetched from a clip of a multi-planar effect.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImagePlane {
+	Colour,
+	ForwardMotionVector,
+	BackwardMotionVector,
+	StereoDisparityLeft,
+	StereoDisparityRight,
+	#[cfg(feature = "natron")]
+	Custom(PlaneComponents),
+}
+
+#[cfg(feature = "natron")]
+enum PlaneField {
+	Name,
+	Label,
+	ChannelsLabel,
+	Channel,
+}
+
+#[cfg(feature = "natron")]
+impl PlaneComponents {
+	pub fn new(name: &str, channels: &[&str]) -> Self {
+		PlaneComponents {
+			name: name.to_owned(),
+			label: None,
+			channels_label: None,
+			channels: channels.iter().map(|&channel| channel.to_owned()).collect(),
+		}
+	}
+
+	pub fn with_label(mut self, label: &str) -> Self {
+		self.label = Some(label.to_owned());
+		self
+	}
+
+	pub fn with_channels_label(mut self, channels_label: &str) -> Self {
+		self.channels_label = Some(channels_label.to_owned());
+		self
+	}
+
+	pub fn num_channels(&self) -> usize {
+		self.channels.len()
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		let prefix = static_bytes_to_string(kNatronOfxImageComponentsPlaneName);
+		if !value.starts_with(&prefix) {
+			return None;
+		}
+		let markers = [
+			(
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneLabel),
+				PlaneField::Label,
+			),
+			(
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneChannelsLabel),
+				PlaneField::ChannelsLabel,
+			),
+			(
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneChannel),
+				PlaneField::Channel,
+			),
+		];
+
+		let mut plane = PlaneComponents {
+			name: String::new(),
+			label: None,
+			channels_label: None,
+			channels: Vec::new(),
+		};
+		let mut field = &PlaneField::Name;
+		let mut rest = &value[prefix.len()..];
+		loop {
+			let next = markers
+				.iter()
+				.filter_map(|(marker, next_field)| {
+					rest.find(marker.as_str())
+						.map(|position| (position, marker.len(), next_field))
+				})
+				.min_by_key(|&(position, _, _)| position);
+			let end = next
+				.map(|(position, _, _)| position)
+				.unwrap_or_else(|| rest.len());
+			let text = rest[..end].to_owned();
+			match *field {
+				PlaneField::Name => plane.name = text,
+				PlaneField::Label => plane.label = Some(text),
+				PlaneField::ChannelsLabel => plane.channels_label = Some(text),
+				PlaneField::Channel => plane.channels.push(text),
+			}
+			match next {
+				Some((position, marker_len, next_field)) => {
+					rest = &rest[position + marker_len..];
+					field = next_field;
+				}
+				None => break,
+			}
+		}
+
+		if plane.name.is_empty() {
+			None
+		} else {
+			Some(plane)
+		}
+	}
+}
+
+#[cfg(feature = "natron")]
+impl fmt::Display for PlaneComponents {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{}{}",
+			static_bytes_to_string(kNatronOfxImageComponentsPlaneName),
+			self.name
+		)?;
+		if let Some(ref label) = self.label {
+			write!(
+				f,
+				"{}{}",
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneLabel),
+				label
+			)?;
+		}
+		if let Some(ref channels_label) = self.channels_label {
+			write!(
+				f,
+				"{}{}",
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneChannelsLabel),
+				channels_label
+			)?;
+		}
+		for channel in &self.channels {
+			write!(
+				f,
+				"{}{}",
+				static_bytes_to_string(kNatronOfxImageComponentsPlaneChannel),
+				channel
+			)?;
+		}
+		Ok(())
+	}
+}
+
+impl ImageComponents {
+	pub fn from_bytes(ofx_name: &[u8]) -> Option<Self> {
+		if let Some(component) = ImageComponent::from_bytes(ofx_name) {
+			return Some(ImageComponents::Standard(component));
+		}
+		let value = CStr::from_bytes_with_nul(ofx_name).ok()?.to_str().ok()?;
+		Some(ImageComponents::from_str(value))
+	}
+
+	pub fn from_cstring(ofx_value: &CStr) -> Option<Self> {
+		Self::from_bytes(ofx_value.to_bytes_with_nul())
+	}
+
+	fn from_str(value: &str) -> Self {
+		#[cfg(feature = "natron")]
+		{
+			if let Some(plane) = PlaneComponents::parse(value) {
+				return ImageComponents::Plane(plane);
+			}
+		}
+		ImageComponents::Other(value.to_owned())
+	}
+
+	pub fn standard(&self) -> Option<ImageComponent> {
+		match *self {
+			ImageComponents::Standard(component) => Some(component),
+			_ => None,
+		}
+	}
+
+	pub fn is_alpha(&self) -> bool {
+		self.standard()
+			.map(ImageComponent::is_alpha)
+			.unwrap_or(false)
+	}
+
+	pub fn is_rgb(&self) -> bool {
+		self.standard().map(ImageComponent::is_rgb).unwrap_or(false)
+	}
+
+	pub fn num_channels(&self) -> Option<usize> {
+		match *self {
+			ImageComponents::Standard(component) => Some(component.num_channels()),
+			#[cfg(feature = "natron")]
+			ImageComponents::Plane(ref plane) => Some(plane.num_channels()),
+			ImageComponents::Other(_) => None,
+		}
+	}
+
+	pub fn to_cstring(&self) -> CString {
+		match *self {
+			ImageComponents::Standard(component) => {
+				CString::new(&component.to_bytes()[..component.to_bytes().len() - 1])
+					.unwrap_or_default()
+			}
+			ref other => CString::new(other.to_string()).unwrap_or_default(),
+		}
+	}
+}
+
+impl From<ImageComponent> for ImageComponents {
+	fn from(component: ImageComponent) -> Self {
+		ImageComponents::Standard(component)
+	}
+}
+
+impl PartialEq<ImageComponent> for ImageComponents {
+	fn eq(&self, other: &ImageComponent) -> bool {
+		self.standard() == Some(*other)
+	}
+}
+
+impl fmt::Display for ImageComponents {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			ImageComponents::Standard(component) => {
+				write!(f, "{}", static_bytes_to_string(component.to_bytes()))
+			}
+			#[cfg(feature = "natron")]
+			ImageComponents::Plane(ref plane) => write!(f, "{}", plane),
+			ImageComponents::Other(ref other) => write!(f, "{}", other),
+		}
+	}
+}
+
+impl ImagePlane {
+	pub fn from_bytes(ofx_name: &[u8]) -> Option<Self> {
+		let plane = match ofx_name {
+			name if name == kFnOfxImagePlaneColour => ImagePlane::Colour,
+			name if name == kFnOfxImagePlaneForwardMotionVector => ImagePlane::ForwardMotionVector,
+			name if name == kFnOfxImagePlaneBackwardMotionVector => {
+				ImagePlane::BackwardMotionVector
+			}
+			name if name == kFnOfxImagePlaneStereoDisparityLeft => ImagePlane::StereoDisparityLeft,
+			name if name == kFnOfxImagePlaneStereoDisparityRight => {
+				ImagePlane::StereoDisparityRight
+			}
+			#[cfg(feature = "natron")]
+			name => {
+				let value = CStr::from_bytes_with_nul(name).ok()?.to_str().ok()?;
+				ImagePlane::Custom(PlaneComponents::parse(value)?)
+			}
+			#[cfg(not(feature = "natron"))]
+			_ => return None,
+		};
+		Some(plane)
+	}
+
+	pub fn from_cstring(ofx_value: &CStr) -> Option<Self> {
+		Self::from_bytes(ofx_value.to_bytes_with_nul())
+	}
+
+	pub fn is_colour(&self) -> bool {
+		*self == ImagePlane::Colour
+	}
+
+	pub fn to_cstring(&self) -> CString {
+		CString::new(self.to_string()).unwrap_or_default()
+	}
+}
+
+impl fmt::Display for ImagePlane {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let name: &[u8] = match *self {
+			ImagePlane::Colour => kFnOfxImagePlaneColour,
+			ImagePlane::ForwardMotionVector => kFnOfxImagePlaneForwardMotionVector,
+			ImagePlane::BackwardMotionVector => kFnOfxImagePlaneBackwardMotionVector,
+			ImagePlane::StereoDisparityLeft => kFnOfxImagePlaneStereoDisparityLeft,
+			ImagePlane::StereoDisparityRight => kFnOfxImagePlaneStereoDisparityRight,
+			#[cfg(feature = "natron")]
+			ImagePlane::Custom(ref plane) => return write!(f, "{}", plane),
+		};
+		write!(f, "{}", static_bytes_to_string(name))
+	}
+}
+
+mod tests {
+	use super::*;
+
+	#[cfg(feature = "natron")]
+	#[test]
+	fn parse_custom_plane() {
+		let plane = PlaneComponents::parse(
+			"NatronOfxImageComponentsPlaneName_fr.unique.id.position_PlaneLabel_Position_Channel_X_Channel_Y_Channel_Z",
+		)
+		.unwrap();
+		assert!(plane.name == "fr.unique.id.position");
+		assert!(plane.label == Some("Position".to_owned()));
+		assert!(plane.channels_label == None);
+		assert!(plane.channels == vec!["X", "Y", "Z"]);
+	}
+
+	#[cfg(feature = "natron")]
+	#[test]
+	fn custom_plane_round_trip() {
+		let plane =
+			PlaneComponents::new("DisparityLeft", &["X", "Y"]).with_channels_label("Disparity");
+		let encoded = plane.to_string();
+		assert!(
+			encoded
+				== "NatronOfxImageComponentsPlaneName_DisparityLeft_ChannelsLabel_Disparity_Channel_X_Channel_Y"
+		);
+		assert!(PlaneComponents::parse(&encoded) == Some(plane));
+	}
+
+	#[test]
+	fn components_from_bytes() {
+		assert!(
+			ImageComponents::from_bytes(kOfxImageComponentRGBA)
+				== Some(ImageComponents::Standard(ImageComponent::RGBA))
+		);
+		assert!(
+			ImageComponents::from_bytes(b"SomeHostSpecificComponents\0")
+				== Some(ImageComponents::Other(
+					"SomeHostSpecificComponents".to_owned()
+				))
+		);
+		assert!(ImagePlane::from_bytes(kFnOfxImagePlaneColour) == Some(ImagePlane::Colour));
+	}
+}
@@ -1,8 +1,15 @@
 #![feature(plugin)]
 
 use action::*;
+use capabilities::*;
+use coordinates::*;
 use enums::*;
 use handle::*;
+use image::*;
+#[cfg(feature = "instrumentation")]
+use instrumentation::*;
+use logging::*;
+use memory::*;
 use ofx_sys::*;
 use property::*;
 use result::*;
@@ -10,51 +17,15 @@ use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::fmt::Display;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
 use suites::*;
+use thread::*;
 use types::*;
+use util::static_bytes_to_string;
 
 pub struct ApiVersion(pub Int);
 pub struct PluginVersion(pub UnsignedInt, pub UnsignedInt);
 
-#[derive(Debug)]
-struct EnumIndex<T>
-where
-	T: std::cmp::Eq + std::hash::Hash + Clone,
-{
-	map: HashMap<String, T>,
-	inverse_map: HashMap<T, String>,
-}
-
-impl<T> EnumIndex<T>
-where
-	T: std::cmp::Eq + std::hash::Hash + Clone,
-{
-	pub fn new() -> EnumIndex<T> {
-		EnumIndex {
-			map: HashMap::new(),
-			inverse_map: HashMap::new(),
-		}
-	}
-
-	pub fn insert(&mut self, key_bytes: &[u8], value: T) {
-		if let Ok(cstr) = CStr::from_bytes_with_nul(key_bytes) {
-			if let Ok(key) = cstr.to_str() {
-				self.map.insert(key.to_owned(), value.clone());
-				self.inverse_map.insert(value, key.to_owned());
-			}
-		} else {
-			error!("Was unable to add {:?} key, this is a bug", key_bytes)
-		}
-	}
-
-	pub fn find(&self, c_key: &[u8]) -> Option<T> {
-		let cstr = CString::new(c_key).ok()?;
-		let key = cstr.into_string().ok()?;
-		self.map.get(&key).cloned()
-	}
-}
-
 #[derive(Debug)]
 pub enum RawMessage {
 	SetHost {
@@ -69,30 +40,43 @@ pub enum RawMessage {
 }
 
 pub trait Dispatch {
-	fn dispatch(&mut self, message: RawMessage) -> Result<Int> {
+	fn dispatch(&self, message: RawMessage) -> Result<Int> {
 		OK
 	}
 }
 
 pub trait Plugin: Dispatch + MapAction + Execute {
-	fn suites(&self) -> &Suites;
+	fn suites(&self) -> Result<Suites>;
 }
 
 pub struct PluginDescriptor {
 	plugin_id: CString,
 	module_name: String,
 	plugin_index: usize,
-	host: Option<OfxHost>,
-	suites: Option<Suites>,
-	cached_handle: Option<ImageEffectHandle>,
+	host: RwLock<Option<OfxHost>>,
+	suites: RwLock<Option<Suites>>,
+	host_capabilities: RwLock<Option<Arc<HostCapabilities>>>,
+	cached_handle: Mutex<Option<ImageEffectDescriptorHandle>>,
+	render_thread_safety: RwLock<ImageEffectRender>,
+	render_lock: Mutex<()>,
+	instance_render_locks: Mutex<HashMap<usize, Arc<Mutex<()>>>>,
+	instance_handle_caches: Mutex<HashMap<usize, Arc<HandleCache>>>,
+	context_clip_names: Mutex<Vec<(ImageEffectContext, Arc<Vec<CString>>)>>,
+	#[cfg(feature = "instrumentation")]
+	instrumentation: Instrumentation,
 	instance: Box<Execute>,
-	global_action_index: EnumIndex<GlobalAction>,
-	image_effect_action_index: EnumIndex<ImageEffectAction>,
 	ofx_plugin: OfxPlugin, // need an owned copy for the lifetime of the plugin
 }
 
+// OfxHost is owned by the host and valid until unload, OfxPlugin only points
+// to static strings, to the plugin id owned by the descriptor and to functions.
+// Everything that changes after registration is behind a lock.
+unsafe impl Send for PluginDescriptor {}
+unsafe impl Sync for PluginDescriptor {}
+
 pub struct PluginContext {
 	host: ImageEffectHost,
+	host_capabilities: Arc<HostCapabilities>,
 	suites: Suites,
 }
 
@@ -112,30 +96,136 @@ impl PluginContext {
 		self.host.clone()
 	}
 
+	pub fn host_capabilities(&self) -> &HostCapabilities {
+		&self.host_capabilities
+	}
+
+	pub fn allocator(&self) -> HostAllocator {
+		HostAllocator::new(self.suites.memory(), std::ptr::null_mut())
+	}
+
+	pub fn suites(&self) -> &Suites {
+		&self.suites
+	}
+
+	/// None if the host has no message suite
+	pub fn message_logger(&self, level: log::LevelFilter) -> Option<HostMessageLogger> {
+		self.suites
+			.message()
+			.map(|message| HostMessageLogger::new(message, level))
+	}
+
+	// without the multithread suite everything runs on the calling thread
 	pub fn num_threads(&self) -> Result<u32> {
+		let multi_thread = match self.suites.multi_thread() {
+			Some(multi_thread) => multi_thread,
+			None => return Ok(1),
+		};
 		let mut c_num_threads: UnsignedInt = 0;
-		to_result! { suite_call!(multiThreadNumCPUs in self.suites.multi_thread; &mut c_num_threads as *mut UnsignedInt)
+		to_result! { suite_call!(multiThreadNumCPUs in multi_thread; &mut c_num_threads as *mut UnsignedInt)
 		=> c_num_threads}
 	}
 
+	pub fn thread_index(&self) -> Result<u32> {
+		let multi_thread = match self.suites.multi_thread() {
+			Some(multi_thread) => multi_thread,
+			None => return Ok(0),
+		};
+		let mut c_thread_index: UnsignedInt = 0;
+		to_result! { suite_call!(multiThreadIndex in multi_thread; &mut c_thread_index as *mut UnsignedInt)
+		=> c_thread_index}
+	}
+
+	pub fn is_spawned_thread(&self) -> Result<bool> {
+		match self.suites.multi_thread() {
+			Some(multi_thread) => Ok(suite_call!(multiThreadIsSpawnedThread in multi_thread;) != 0),
+			None => Ok(false),
+		}
+	}
+
+	// the host may still call actions from several threads, so there is no
+	// safe fallback for a mutex
+	pub fn new_mutex<T>(&self, value: T) -> Result<HostMutex<T>> {
+		let multi_thread = self
+			.suites
+			.multi_thread()
+			.ok_or(Error::MissingHostFeature)?;
+		HostMutex::new(multi_thread, value)
+	}
+
+	/// Runs function on disjoint tiles of the image across the host threads.
+	/// Stops early on the first error or panic, which is returned, or when the
+	/// effect is aborted, so the caller should check abort() afterwards.
+	pub fn par_tiles<'a, T, F>(
+		&self,
+		effect: &ImageEffectHandle,
+		image: ImageDescriptorMut<'a, T>,
+		function: F,
+	) -> Result<()>
+	where
+		T: PixelFormat + Send + 'a,
+		F: Fn(&mut ImageTileMut<'a, T>) -> Result<()> + Sync,
+	{
+		let num_threads = self.num_threads().unwrap_or(1).max(1);
+		// a few tiles per thread to even out the load
+		let tiles = image.into_bands(num_threads as usize * 4);
+		let parallel_tiles = ParallelTiles::new(tiles, &function, effect);
+		let multi_thread = self.suites.multi_thread();
+		run_shared_in_threads(multi_thread.as_deref(), num_threads, &parallel_tiles);
+		parallel_tiles.into_result()
+	}
+
+	/// Runs function on each row of the image within window, as (y, row slice)
+	pub fn par_rows<'a, T, F>(
+		&self,
+		effect: &ImageEffectHandle,
+		image: ImageDescriptorMut<'a, T>,
+		window: RectI,
+		function: F,
+	) -> Result<()>
+	where
+		T: PixelFormat + Send + 'a,
+		F: Fn(Int, &mut [T]) -> Result<()> + Sync,
+	{
+		let window = window.intersection(&image.bounds());
+		// the intersection of disjoint rectangles keeps a corner outside the image
+		if window.is_empty() {
+			return Ok(());
+		}
+		self.par_tiles(effect, image, |tile| {
+			let rows = tile.bounds().intersection(&window);
+			for y in rows.y1..rows.y2 {
+				function(y, tile.row_range(window.x1, window.x2, y))?;
+			}
+			Ok(())
+		})
+	}
+
 	fn run_in_threads_internal(
 		&self,
+		multi_thread: &OfxMultiThreadSuiteV1,
 		function: ThreadFunction,
 		n_threads: UnsignedInt,
 		custom_arg: VoidPtrMut,
 	) -> Result<()> {
-		suite_fn!(multiThread in self.suites.multi_thread; function, n_threads, custom_arg)
+		suite_fn!(multiThread in *multi_thread; function, n_threads, custom_arg)
 	}
 
+	/// Runs on n_threads host threads, or once on the calling thread as thread 0 of 1
+	/// if the host has no multithread suite
 	pub fn run_in_threads<R>(&self, n_threads: UnsignedInt, runnable: &mut R) -> Result<()>
 	where
 		R: Runnable,
 	{
-		self.run_in_threads_internal(
-			Some(R::run_myself),
-			n_threads,
-			(runnable as *mut R) as VoidPtrMut,
-		)?;
+		match self.suites.multi_thread() {
+			Some(multi_thread) => self.run_in_threads_internal(
+				&multi_thread,
+				Some(R::run_myself),
+				n_threads,
+				(runnable as *mut R) as VoidPtrMut,
+			)?,
+			None => runnable.run(0, 1),
+		}
 		Ok(())
 	}
 }
@@ -188,27 +278,56 @@ impl MapAction for PluginDescriptor {
 					))
 			};
 		};
-		let name = unsafe { CStr::from_ptr(action) }.to_bytes();
-		if let Some(action) = self.image_effect_action_index.find(name) {
+		let name = unsafe { CStr::from_ptr(action) }
+			.to_str()
+			.unwrap_or_default();
+		if let Some(action) = ImageEffectAction::from_name(name) {
 			use ImageEffectAction::*;
 			match action {
-				DescribeInContext => map_args! { DescribeInContext(in_args) },
+				DescribeInContext => Ok(Action::DescribeInContext(
+					self.new_image_effect_descriptor_raw(handle)?,
+					self.typed_properties(build_typed::<DescribeInContextInArgs>, in_args)?,
+				)),
 				GetRegionOfDefinition => map_args! { GetRegionOfDefinition(in_args, out_args) },
-				GetRegionsOfInterest => map_args! { GetRegionsOfInterest(in_args, out_args) },
+				GetRegionsOfInterest => {
+					let effect = self.new_image_effect_raw(handle)?;
+					let clip_names = self.context_clip_names(&effect)?;
+					let in_args =
+						self.typed_properties(build_typed::<GetRegionsOfInterestInArgs>, in_args)?;
+					let out_args = self
+						.typed_properties(build_typed::<GetRegionsOfInterestOutArgs>, out_args)?;
+					Ok(Action::GetRegionsOfInterest(
+						effect,
+						in_args,
+						out_args.with_clip_names(clip_names),
+					))
+				}
 				IsIdentity => map_args! { IsIdentity(in_args, out_args) },
-				GetClipPreferences => map_args! { GetClipPreferences(out_args) },
+				GetClipPreferences => {
+					let effect = self.new_image_effect_raw(handle)?;
+					let clip_names = self.context_clip_names(&effect)?;
+					let out_args =
+						self.typed_properties(build_typed::<GetClipPreferencesOutArgs>, out_args)?;
+					Ok(Action::GetClipPreferences(
+						effect,
+						out_args.with_clip_names(clip_names),
+					))
+				}
 				GetTimeDomain => map_args!(GetTimeDomain(out_args)),
 				BeginSequenceRender => map_args! { BeginSequenceRender(in_args) },
 				Render => map_args! { Render(in_args) },
 				EndSequenceRender => map_args!(EndSequenceRender(in_args)),
+				GetClipComponents => map_args! { GetClipComponents(in_args, out_args) },
 				_ => Err(Error::InvalidAction),
 			}
-		} else if let Some(action) = self.global_action_index.find(name) {
+		} else if let Some(action) = GlobalAction::from_name(name) {
 			use GlobalAction::*;
 			match action {
 				Load => Ok(Action::Load),     // handled by the library
 				Unload => Ok(Action::Unload), // handled by the library
-				Describe => map_args!(Describe()),
+				Describe => Ok(Action::Describe(
+					self.new_image_effect_descriptor_raw(handle)?,
+				)),
 				SyncPrivateData => map_args! { SyncPrivateData() },
 				PurgeCaches => map_args! { PurgeCaches() },
 				CreateInstance => map_args!(CreateInstance()),
@@ -219,7 +338,7 @@ impl MapAction for PluginDescriptor {
 				_ => Err(Error::InvalidAction),
 			}
 		} else {
-			warn!("map_action: No action matching {:?}", unsafe {
+			warn!(target: self.module_name.as_str(), "map_action: No action matching {:?}", unsafe {
 				CStr::from_ptr(action)
 			});
 			Err(Error::InvalidAction)
@@ -228,7 +347,7 @@ impl MapAction for PluginDescriptor {
 }
 
 impl Filter for PluginDescriptor {
-	fn before_execute(&mut self, action: &Action) -> Result<Int> {
+	fn before_execute(&self, action: &Action) -> Result<Int> {
 		match action {
 			Action::Load => self.load(),
 			Action::Unload => self.unload(),
@@ -240,13 +359,25 @@ impl Filter for PluginDescriptor {
 	}
 
 	fn after_execute(
-		&mut self,
+		&self,
 		context: &PluginContext,
 		action: &mut Action,
 		_status: Result<Int>,
 	) -> Result<Int> {
 		match action {
-			Action::DestroyInstance(ref mut effect) => effect.drop_instance_data(),
+			Action::Describe(ref effect) => self.read_render_thread_safety(effect),
+			Action::DescribeInContext(ref effect, ref in_args) => {
+				self.record_clip_names(in_args.get_context()?, effect)
+			}
+			Action::DestroyInstance(ref mut effect) => {
+				self.instance_render_locks
+					.lock()?
+					.remove(&effect.instance_key());
+				self.instance_handle_caches
+					.lock()?
+					.remove(&effect.instance_key());
+				effect.drop_instance_data()
+			}
 			_ => Ok(()),
 		}?;
 
@@ -255,10 +386,10 @@ impl Filter for PluginDescriptor {
 }
 
 impl Dispatch for PluginDescriptor {
-	fn dispatch(&mut self, message: RawMessage) -> Result<Int> {
+	fn dispatch(&self, message: RawMessage) -> Result<Int> {
 		match message {
 			RawMessage::SetHost { host } => {
-				self.host = Some(host);
+				*self.host.write()? = Some(host);
 				OK
 			}
 			RawMessage::MainEntry {
@@ -269,15 +400,23 @@ impl Dispatch for PluginDescriptor {
 			} => {
 				let mut mapped_action = self.map_action(action, handle, in_args, out_args)?;
 
-				debug!("Mapped action found: {:?}", mapped_action);
+				debug!(target: self.module_name.as_str(), "Mapped action found: {:?}", mapped_action);
 				self.before_execute(&mapped_action)?;
 
-				if let (Some(host), Some(suites)) = (self.host, self.suites.clone()) {
+				let host = *self.host.read()?;
+				let suites = self.suites.read()?.clone();
+				if let (Some(host), Some(suites)) = (host, suites) {
 					let plugin_context = PluginContext {
 						host: ImageEffectHost::new(host.host, suites.property()),
+						host_capabilities: self
+							.host_capabilities
+							.read()?
+							.clone()
+							.unwrap_or_default(),
 						suites,
 					};
-					let status = self.execute(&plugin_context, &mut mapped_action);
+					let status =
+						self.execute_instrumented(action, &plugin_context, &mut mapped_action);
 					self.after_execute(&plugin_context, &mut mapped_action, status)?;
 					status
 				} else {
@@ -289,11 +428,13 @@ impl Dispatch for PluginDescriptor {
 }
 
 impl Execute for PluginDescriptor {
-	fn execute(&mut self, context: &PluginContext, action: &mut Action) -> Result<Int> {
+	fn execute(&self, context: &PluginContext, action: &mut Action) -> Result<Int> {
 		let result = self.instance.execute(context, action);
 		debug!(
-			"Executed {:?} of {} -> {:?}",
-			action, self.module_name, result
+			target: self.module_name.as_str(),
+			"Executed {:?} -> {:?}",
+			action,
+			result
 		);
 		result
 	}
@@ -322,78 +463,32 @@ impl PluginDescriptor {
 			mainEntry: Some(main_entry),
 		};
 
-		let mut global_action_index = EnumIndex::new();
-		let mut image_effect_action_index = EnumIndex::new();
-		use ofx_sys::*;
-		macro_rules! global_add {
-			($id:ident) => {
-				debug!(
-					"kOfxAction{} GlobalAction::{}",
-					stringify!($id),
-					stringify!($id)
-					);
-				global_action_index.insert(concat_idents!(kOfxAction, $id), GlobalAction::$id)
-			};
-		}
-		macro_rules! image_effect_add {
-			($id:ident) => {
-				debug!(
-					"kOfxImageEffectAction{} ImageEffectAction::{}",
-					stringify!($id),
-					stringify!($id)
-					);
-				image_effect_action_index.insert(
-					concat_idents!(kOfxImageEffectAction, $id),
-					ImageEffectAction::$id,
-					)
-			};
-		}
-
-		global_add!(Load);
-		global_add!(Describe);
-		global_add!(Unload);
-		global_add!(PurgeCaches);
-		global_add!(SyncPrivateData);
-		global_add!(CreateInstance);
-		global_add!(DestroyInstance);
-		global_add!(BeginInstanceChanged);
-		global_add!(InstanceChanged);
-		global_add!(EndInstanceChanged);
-		global_add!(BeginInstanceEdit);
-		global_add!(EndInstanceEdit);
-		global_add!(Dialog);
-
-		image_effect_add!(GetRegionOfDefinition);
-		image_effect_add!(GetRegionsOfInterest);
-		image_effect_add!(GetTimeDomain);
-		image_effect_add!(GetFramesNeeded);
-		image_effect_add!(GetClipPreferences);
-		image_effect_add!(IsIdentity);
-		image_effect_add!(BeginSequenceRender);
-		image_effect_add!(Render);
-		image_effect_add!(EndSequenceRender);
-		image_effect_add!(DescribeInContext);
-		image_effect_add!(GetInverseDistortion);
-		image_effect_add!(InvokeHelp);
-		image_effect_add!(InvokeAbout);
-		image_effect_add!(VegasKeyframeUplift);
-
 		PluginDescriptor {
 			plugin_index,
 			module_name: module_name.to_owned(),
 			plugin_id,
 			instance,
-			host: None,
-			suites: None,
-			cached_handle: None,
-			global_action_index,
-			image_effect_action_index,
+			host: RwLock::new(None),
+			suites: RwLock::new(None),
+			host_capabilities: RwLock::new(None),
+			cached_handle: Mutex::new(None),
+			// the default when the plugin does not set the property
+			render_thread_safety: RwLock::new(ImageEffectRender::InstanceSafe),
+			render_lock: Mutex::new(()),
+			instance_render_locks: Mutex::new(HashMap::new()),
+			instance_handle_caches: Mutex::new(HashMap::new()),
+			context_clip_names: Mutex::new(Vec::new()),
+			#[cfg(feature = "instrumentation")]
+			instrumentation: Instrumentation::new(),
 			ofx_plugin,
 		}
 	}
 
-	fn suites(&self) -> Result<&Suites> {
-		self.suites.as_ref().ok_or(Error::SuiteNotInitialized)
+	fn suites(&self) -> Result<Suites> {
+		self.suites
+			.read()?
+			.clone()
+			.ok_or(Error::SuiteNotInitialized)
 	}
 
 	fn new_image_effect_raw(&self, ptr: VoidPtr) -> Result<ImageEffectHandle> {
@@ -405,107 +500,397 @@ impl PluginDescriptor {
 		let property_suite = suites.property();
 		let image_effect_suite = suites.image_effect();
 		let parameter_suite = suites.parameter();
-		Ok(ImageEffectHandle::new(
+		let image_effect_plane_suite = suites.image_effect_plane();
+		let effect = ImageEffectHandle::new(
 			handle,
 			property_suite,
 			image_effect_suite,
 			parameter_suite,
-		))
+			image_effect_plane_suite,
+		);
+		let handle_cache = self.instance_handle_cache(effect.instance_key())?;
+		let effect = effect.with_handle_cache(Some(handle_cache));
+		#[cfg(feature = "camera")]
+		let effect = effect.with_camera(suites.camera());
+		Ok(effect)
+	}
+
+	fn new_image_effect_descriptor_raw(&self, ptr: VoidPtr) -> Result<ImageEffectDescriptorHandle> {
+		let suites = self.suites()?;
+		let effect = ImageEffectDescriptorHandle::new(
+			unsafe { ptr as OfxImageEffectHandle },
+			suites.property(),
+			suites.image_effect(),
+			suites.parameter(),
+		);
+		#[cfg(feature = "camera")]
+		let effect = effect.with_camera(suites.camera());
+		Ok(effect)
+	}
+
+	fn instance_handle_cache(&self, instance_key: usize) -> Result<Arc<HandleCache>> {
+		Ok(self
+			.instance_handle_caches
+			.lock()?
+			.entry(instance_key)
+			.or_insert_with(Default::default)
+			.clone())
 	}
 
 	fn typed_properties<T, F>(&self, constructor: F, handle: OfxPropertySetHandle) -> Result<T>
 	where
-		F: Fn(OfxPropertySetHandle, Rc<OfxPropertySuiteV1>) -> T,
+		F: Fn(OfxPropertySetHandle, Arc<OfxPropertySuiteV1>) -> T,
 	{
 		let property_suite = self.suites()?.property();
 		Ok(constructor(handle, property_suite))
 	}
 
-	fn load(&mut self) -> Result<Int> {
-		let host = self.host.ok_or(Error::HostNotReady)?;
+	fn load(&self) -> Result<Int> {
+		let host = self.host.read()?.ok_or(Error::HostNotReady)?;
 		let fetch_suite = host.fetchSuite.ok_or(Error::HostNotReady)?;
 
 		const V1: Int = 1;
 		const V2: Int = 2;
 
 		debug!("Fetching suites");
+		let mut fetched_suites = Vec::new();
 		macro_rules! fetch_suite {
+			// optional suites and probed versions are only worth an info message
+			(required $($suite:tt)*) => {
+				match fetch_suite!($($suite)*) {
+					Some(suite) => suite,
+					None => {
+						error!(target: self.module_name.as_str(), "Required suite {} not available", stringify!($($suite)*));
+						return Err(Error::MissingHostFeature);
+					}
+				}
+			};
 			($suite_name:ident, $suite_version:ident) => {
+				fetch_suite!(@fetch
+					stringify!($suite_name),
+					concat_idents!(kOfx, $suite_name, Suite),
+					concat_idents!(Ofx, $suite_name, Suite, $suite_version),
+					$suite_version
+				)
+			};
+			($suite_id:ident as $suite_type:ident, $suite_version:ident) => {
+				fetch_suite!(@fetch stringify!($suite_type), $suite_id, $suite_type, $suite_version)
+			};
+			(@fetch $label:expr, $suite_id:expr, $suite_type:ty, $suite_version:ident) => {
 				unsafe {
 					let suiteptr = fetch_suite(
 						host.host as OfxPropertySetHandle,
-						CStr::from_bytes_with_nul_unchecked(concat_idents!(
-							kOfx,
-							$suite_name,
-							Suite
-						))
-						.as_ptr(),
+						CStr::from_bytes_with_nul_unchecked($suite_id).as_ptr(),
 						$suite_version,
 						);
 					if suiteptr.is_null() {
-						error!("Failed to load {}", stringify!($suite_name));
+						info!("Host does not offer {} version {}", $label, $suite_version);
 						None
 					} else {
-						debug!(
-							"Found suite '{}' at {:?}",
-							stringify!($suite_name),
-							suiteptr
-						);
-						unsafe {
-							Some(*unsafe {
-								suiteptr
-									as *const concat_idents!(
-										Ofx,
-										$suite_name,
-										Suite,
-										$suite_version
-									)
-							})
-							}
-						}
+						debug!("Found suite '{}' at {:?}", $label, suiteptr);
+						fetched_suites.push(SuiteVersion {
+							name: static_bytes_to_string($suite_id),
+							version: $suite_version,
+						});
+						Some(*(suiteptr as *const $suite_type))
 					}
+				}
 			};
 		};
 
-		self.suites = Some(Suites::new(
-			fetch_suite!(ImageEffect, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Property, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Parameter, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Memory, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(MultiThread, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Message, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Message, V2),
-			fetch_suite!(Progress, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(Progress, V2),
-			fetch_suite!(TimeLine, V1).ok_or(Error::InvalidSuite)?,
-			fetch_suite!(ParametricParameter, V1),
-			fetch_suite!(ImageEffectOpenGLRender, V1),
+		// newer versions are probed first, the older ones only if missing
+		let message_v2 = fetch_suite!(Message, V2);
+		let message = match message_v2 {
+			Some(ref suite) => Some(OfxMessageSuiteV1 {
+				message: suite.message,
+			}),
+			None => fetch_suite!(Message, V1),
+		};
+		let progress = match fetch_suite!(Progress, V2) {
+			Some(suite) => Some(ProgressSuite::V2(Arc::new(suite))),
+			None => fetch_suite!(Progress, V1).map(|suite| ProgressSuite::V1(Arc::new(suite))),
+		};
+		let image_effect_plane = match fetch_suite!(
+			kFnOfxImageEffectPlaneSuite as FnOfxImageEffectPlaneSuiteV2,
+			V2
+		) {
+			Some(suite) => Some(ImageEffectPlaneSuite::V2(Arc::new(suite))),
+			None => fetch_suite!(
+				kFnOfxImageEffectPlaneSuite as FnOfxImageEffectPlaneSuiteV1,
+				V1
+			)
+			.map(|suite| ImageEffectPlaneSuite::V1(Arc::new(suite))),
+		};
+
+		let suites = Suites::new(
+			fetch_suite!(required ImageEffect, V1),
+			fetch_suite!(required Property, V1),
+			fetch_suite!(required Parameter, V1),
+			fetch_suite!(Memory, V1),
+			fetch_suite!(MultiThread, V1),
+			message,
+			message_v2,
+			progress,
+			fetch_suite!(TimeLine, V1),
+			image_effect_plane,
+		);
+		#[cfg(feature = "parametric")]
+		let suites = suites.with_parametric_parameter(fetch_suite!(ParametricParameter, V1));
+		#[cfg(feature = "opengl")]
+		let suites = suites.with_image_effect_opengl_render(fetch_suite!(ImageEffectOpenGLRender, V1));
+		#[cfg(feature = "camera")]
+		let suites = suites.with_camera(fetch_suite!(
+			kNukeOfxCameraSuite as NukeOfxCameraSuiteV1,
+			V1
 		));
-		info!("Loaded plugin");
+
+		if let Some(missing) = suites.find_missing(self.instance.required_suites()) {
+			error!(target: self.module_name.as_str(), "Required suite {:?} not available", missing);
+			return Err(Error::MissingHostFeature);
+		}
+
+		let host_capabilities = HostCapabilities::new(
+			&ImageEffectHost::new(host.host, suites.property()),
+			fetched_suites,
+		);
+		info!(target: self.module_name.as_str(), "Host capabilities {}", host_capabilities);
+		*self.suites.write()? = Some(suites);
+		*self.host_capabilities.write()? = Some(Arc::new(host_capabilities));
+		info!(target: self.module_name.as_str(), "Loaded plugin");
 		OK
 	}
 
-	fn unload(&mut self) -> Result<Int> {
+	fn unload(&self) -> Result<Int> {
+		#[cfg(feature = "instrumentation")]
+		info!(
+			target: self.module_name.as_str(),
+			"Instrumentation summary\n{}",
+			self.instrumentation
+		);
 		OK
 	}
 
-	fn cache_handle(&mut self, handle: ImageEffectHandle) {
-		self.cached_handle = Some(handle);
+	fn cache_handle(&self, handle: ImageEffectDescriptorHandle) -> Result<()> {
+		*self.cached_handle.lock()? = Some(handle);
+		Ok(())
 	}
 
-	fn describe(&mut self, handle: ImageEffectHandle) -> Result<Int> {
-		info!("Caching plugin instance handle {:?}", handle);
-		self.cache_handle(handle);
+	fn describe(&self, handle: ImageEffectDescriptorHandle) -> Result<Int> {
+		info!(target: self.module_name.as_str(), "Caching plugin descriptor handle {:?}", handle);
+		self.cache_handle(handle)?;
 		OK
 	}
 
+	fn read_render_thread_safety(&self, effect: &ImageEffectDescriptorHandle) -> Result<()> {
+		let effect_properties: EffectDescriptor = effect.properties()?;
+		if let Ok(render_thread_safety) = effect_properties.get_render_thread_safety() {
+			info!(target: self.module_name.as_str(), "Render thread safety {:?}", render_thread_safety);
+			*self.render_thread_safety.write()? = render_thread_safety;
+		}
+		Ok(())
+	}
+
+	fn record_clip_names(
+		&self,
+		context: ImageEffectContext,
+		effect: &ImageEffectDescriptorHandle,
+	) -> Result<()> {
+		let clip_names = Arc::new(effect.clip_names()?);
+		let mut context_clip_names = self.context_clip_names.lock()?;
+		context_clip_names.retain(|&(known_context, _)| known_context != context);
+		context_clip_names.push((context, clip_names));
+		Ok(())
+	}
+
+	// None when the instance context was never described, nothing to check against
+	fn context_clip_names(&self, effect: &ImageEffectHandle) -> Result<Option<Arc<Vec<CString>>>> {
+		let effect_properties: EffectInstance = effect.properties()?;
+		let context = effect_properties.get_context()?;
+		Ok(self
+			.context_clip_names
+			.lock()?
+			.iter()
+			.find(|&&(known_context, _)| known_context == context)
+			.map(|&(_, ref clip_names)| clip_names.clone()))
+	}
+
+	fn instance_render_lock(&self, instance_key: usize) -> Result<Arc<Mutex<()>>> {
+		Ok(self
+			.instance_render_locks
+			.lock()?
+			.entry(instance_key)
+			.or_insert_with(|| Arc::new(Mutex::new(())))
+			.clone())
+	}
+
+	// hosts may ignore the declared render thread safety, serialise render
+	// calls here so the plugin gets the guarantee it asked for
+	fn execute_render_safe(&self, context: &PluginContext, action: &mut Action) -> Result<Int> {
+		let instance_key = match *action {
+			Action::Render(ref effect, _) => effect.instance_key(),
+			_ => return self.execute(context, action),
+		};
+		let render_thread_safety = *self.render_thread_safety.read()?;
+		match render_thread_safety {
+			ImageEffectRender::Unsafe => {
+				let _render_guard = self.render_lock.lock()?;
+				self.execute(context, action)
+			}
+			ImageEffectRender::InstanceSafe => {
+				let instance_lock = self.instance_render_lock(instance_key)?;
+				let _render_guard = instance_lock.lock()?;
+				self.execute(context, action)
+			}
+			ImageEffectRender::FullySafe => self.execute(context, action),
+		}
+	}
+
+	#[cfg(not(any(feature = "instrumentation", feature = "tracing")))]
+	fn execute_instrumented(
+		&self,
+		_action_name: CharPtr,
+		context: &PluginContext,
+		action: &mut Action,
+	) -> Result<Int> {
+		self.execute_render_safe(context, action)
+	}
+
+	#[cfg(any(feature = "instrumentation", feature = "tracing"))]
+	fn execute_instrumented(
+		&self,
+		action_name: CharPtr,
+		context: &PluginContext,
+		action: &mut Action,
+	) -> Result<Int> {
+		let action_name = unsafe { CStr::from_ptr(action_name) }.to_string_lossy();
+		#[cfg(feature = "tracing")]
+		let _span = tracing::info_span!(
+			"ofx_action",
+			plugin = self.module_name.as_str(),
+			action = &*action_name
+		)
+		.entered();
+		#[cfg(feature = "instrumentation")]
+		let rendering = self.begin_render_stats(context, action);
+		#[cfg(feature = "instrumentation")]
+		let start = std::time::Instant::now();
+		let status = self.execute_render_safe(context, action);
+		#[cfg(feature = "instrumentation")]
+		{
+			if rendering {
+				self.instrumentation.end_render();
+			}
+			let failed = match status {
+				Ok(eOfxStatus_OK) | Ok(eOfxStatus_ReplyDefault) => false,
+				_ => true,
+			};
+			self.instrumentation
+				.record_action(&action_name, start.elapsed(), failed);
+		}
+		status
+	}
+
+	#[cfg(feature = "instrumentation")]
+	fn begin_render_stats(&self, context: &PluginContext, action: &Action) -> bool {
+		if let Action::Render(ref effect, ref in_args) = *action {
+			if let Ok(render_window) = in_args.get_render_window() {
+				let pixel_depth = effect
+					.get_output_clip()
+					.and_then(|clip| clip.get_pixel_depth())
+					.ok();
+				let host_threads = context.num_threads().unwrap_or(1);
+				self.instrumentation
+					.begin_render(render_window, pixel_depth, host_threads);
+				return true;
+			}
+		}
+		false
+	}
+
+	#[cfg(feature = "instrumentation")]
+	pub fn instrumentation(&self) -> &Instrumentation {
+		&self.instrumentation
+	}
+
 	pub fn ofx_plugin(&self) -> &OfxPlugin {
 		&self.ofx_plugin
 	}
 }
 
 impl Plugin for PluginDescriptor {
-	fn suites(&self) -> &Suites {
-		&self.suites.as_ref().unwrap()
+	fn suites(&self) -> Result<Suites> {
+		PluginDescriptor::suites(self)
+	}
+}
+
+mod tests {
+	use super::*;
+	use std::mem;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	// no multithread suite, tiles run on the calling thread
+	fn single_threaded() -> (PluginContext, ImageEffectHandle) {
+		let suites = unsafe {
+			Suites::new(
+				mem::zeroed(),
+				mem::zeroed(),
+				mem::zeroed(),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+		let property: Arc<OfxPropertySuiteV1> = Arc::new(unsafe { mem::zeroed() });
+		let context = PluginContext {
+			host: ImageEffectHost::new(std::ptr::null_mut(), property.clone()),
+			host_capabilities: Arc::new(HostCapabilities::default()),
+			suites,
+		};
+		let effect = unsafe {
+			ImageEffectHandle::new(
+				std::ptr::null_mut(),
+				property,
+				Arc::new(mem::zeroed()),
+				Arc::new(mem::zeroed()),
+				None,
+			)
+		};
+		(context, effect)
+	}
+
+	fn rect(x1: Int, y1: Int, x2: Int, y2: Int) -> RectI {
+		RectI { x1, y1, x2, y2 }
+	}
+
+	#[test]
+	fn par_rows_outside_the_image() {
+		let (context, effect) = single_threaded();
+		let mut pixels = [0.0f32; 16];
+		let bounds = rect(0, 0, 4, 4);
+		let calls = AtomicUsize::new(0);
+		for &window in &[rect(10, 0, 20, 4), rect(0, 10, 4, 20), rect(2, 0, 2, 4)] {
+			let image =
+				ImageDescriptorMut::<f32>::new(bounds, 16, pixels.as_mut_ptr() as VoidPtrMut);
+			let result = context.par_rows(&effect, image, window, |_, _| {
+				calls.fetch_add(1, Ordering::SeqCst);
+				Ok(())
+			});
+			assert!(result.is_ok());
+		}
+		assert!(calls.load(Ordering::SeqCst) == 0);
+
+		let image = ImageDescriptorMut::<f32>::new(bounds, 16, pixels.as_mut_ptr() as VoidPtrMut);
+		let result = context.par_rows(&effect, image, rect(2, 2, 10, 10), |_, row| {
+			for pixel in row.iter_mut() {
+				*pixel = 1.0;
+			}
+			Ok(())
+		});
+		assert!(result.is_ok());
+		assert!(pixels.iter().sum::<f32>() == 4.0);
 	}
 }
@@ -1,15 +1,20 @@
 #![feature(concat_idents)]
 
 use enums::{
-	BitDepth, Change, HostNativeOrigin, IdentifiedEnum, ImageComponent, ImageEffectContext,
-	ImageEffectRender, ImageField, ImageFieldExtraction, ImageFieldOrder, ParamDoubleType,
+	BitDepth, Change, HostNativeOrigin, IdentifiedEnum, Image as EImage, ImageComponent,
+	ImageEffectContext, ImageEffectRender, ImageField, ImageFieldExtraction, ImageFieldOrder,
+	ParamCoordinates, ParamDoubleType, ParamInvalidate, ParamString, ParamType as EParamType,
 	Type as EType,
 };
 use handle::Image;
 use handle::*;
+use introspect::*;
+use name::OfxName;
 use ofx_sys::*;
+use plane::{ImageComponents, ImagePlane};
 use result;
 use result::*;
+use std::borrow::{Borrow, Cow};
 use std::ffi::{CStr, CString};
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -117,19 +122,14 @@ macro_rules! property_define_setter_trait {
 
 	($function_name: ident, $property_name:path, &[enum $enum_value_type:ty]) => {
 		fn $function_name(&mut self, values: &[$enum_value_type]) -> Result<()> {
-			for (index, value) in values.iter().enumerate() {
-				self.set_at::<$property_name>(index, value.to_bytes())?;
-			}
-			Ok(())
+			let values: Vec<&[u8]> = values.iter().map(|value| value.to_bytes()).collect();
+			self.set_all::<$property_name, _>(&values)
 		}
 	};
 
 	($function_name: ident, $property_name:path, &seq [$value_type:ty]) => {
 		fn $function_name(&mut self, values: &[$value_type]) -> Result<()> {
-			for (index, value) in values.iter().enumerate() {
-				self.set_at::<$property_name>(index, value)?;
-			}
-			Ok(())
+			self.set_all::<$property_name, _>(values)
 		}
 	};
 
@@ -172,6 +172,21 @@ macro_rules! property_define_getter_trait {
 		}
 	};
 
+	($function_name: ident, $property_name:path, &[enum $enum_value_type:ident]) => {
+		fn $function_name(&self) -> Result<Vec<$enum_value_type>> {
+			self.get_all::<$property_name>()?
+				.iter()
+				.map(|str_value| $enum_value_type::from_cstring(str_value).ok_or(Error::EnumNotFound))
+				.collect()
+		}
+	};
+
+	($function_name: ident, $property_name:path, &seq) => {
+		fn $function_name(&self) -> Result<Vec<<$property_name as Get>::ReturnType>> {
+			self.get_all::<$property_name>()
+		}
+	};
+
 	($function_name: ident, $property_name:path) => {
 		fn $function_name(&self) -> Result<<$property_name as Get>::ReturnType> {
 			self.get::<$property_name>()
@@ -191,6 +206,9 @@ macro_rules! property {
 		pub mod $name {
 			use super::*;
 			$($tail)*
+			pub fn describe() -> Vec<PropertyDescription> {
+				vec![PropertyDescription::of::<Property>()]
+			}
 		}
 		pub use self::$name::CanGet as $get_name;
 		pub use self::$name::CanSet as $set_name;
@@ -201,10 +219,44 @@ macro_rules! property {
 		pub mod $name {
 			use super::*;
 			$($tail)*
+			pub fn describe() -> Vec<PropertyDescription> {
+				vec![PropertyDescription::of::<Property>()]
+			}
 		}
 		pub use self::$name::CanGet as $get_name;
 	};
 
+	($prop_name:ident as $name:ident {
+		$get_name:ident () -> $get_type:ty as &[enum $enum_get:ident];
+		$set_name:ident (&$set_type:ty as &[enum $enum_set:ty]);
+	}) => {
+		property! { $name, $get_name, $set_name =>
+			property_assign_name!($prop_name as Property: (&$set_type) -> $get_type);
+			property_define_getter_trait!(CanGet => $get_name, Property, &[enum $enum_get]);
+			property_define_setter_trait!(CanSet => $set_name, Property, &[enum $enum_set]);
+		}
+	};
+
+	($prop_name:ident as $name:ident {
+		$get_name:ident () -> $get_type:ty as &seq;
+		$set_name:ident (&seq [&$set_type:ty]);
+	}) => {
+		property! { $name, $get_name, $set_name =>
+			property_assign_name!($prop_name as Property: (&$set_type) -> $get_type);
+			property_define_getter_trait!(CanGet => $get_name, Property, &seq);
+			property_define_setter_trait!(CanSet => $set_name, Property, &seq [&$set_type]);
+		}
+	};
+
+	($prop_name:ident as $name:ident {
+		$get_name:ident () -> $get_type:ty as &seq;
+	}) => {
+		property! { $name, $get_name =>
+			property_assign_name!($prop_name as Property: () -> $get_type);
+			property_define_getter_trait!(CanGet => $get_name, Property, &seq);
+		}
+	};
+
 	($prop_name:ident as $name:ident {
 		$get_name:ident () -> $get_type:ty;
 		$set_name:ident (&$set_type:ty);
@@ -296,28 +348,41 @@ macro_rules! property_group {
 		property_group!(@impl $trait => $($tail)*);
 	};
 
-	($trait:ident { $head1:tt $head2:tt, $($tail:tt)* }) => {
-		pub trait $trait: AsProperties + Clone {}
-		property_group!(@impl $trait => $head1 $head2, $($tail)*);
+	(@describe $list:ident => ) => {};
+
+	(@describe $list:ident => $property:ident $access:ident $(+ $more_access:ident)*, $($tail:tt)*) => {
+		$list.extend($property::describe());
+		property_group!(@describe $list => $($tail)*);
+	};
+
+	($trait:ident { $($tail:tt)* }) => {
+		pub trait $trait: AsProperties + Clone {
+			fn group_properties() -> Vec<PropertyDescription> {
+				let mut properties = Vec::new();
+				property_group!(@describe properties => $($tail)*);
+				properties
+			}
+		}
+		property_group!(@impl $trait => $($tail)*);
 	};
 }
 
 macro_rules! object_properties {
 	(@tail $trait:ty => ) => {};
 
-	(@tail $trait:ty => $property:ident read+write, $($tail:tt)*) => {
-		impl $property::CanGet for $trait {}
-		impl $property::CanSet for $trait {}
+	(@tail $trait:ty => $(#[$meta:meta])* $property:ident read+write, $($tail:tt)*) => {
+		$(#[$meta])* impl $property::CanGet for $trait {}
+		$(#[$meta])* impl $property::CanSet for $trait {}
 		object_properties!(@tail $trait => $($tail)*);
 	};
 
-	(@tail $trait:ty => $property:ident write, $($tail:tt)*) => {
-		impl $property::CanSet for $trait {}
+	(@tail $trait:ty => $(#[$meta:meta])* $property:ident write, $($tail:tt)*) => {
+		$(#[$meta])* impl $property::CanSet for $trait {}
 		object_properties!(@tail $trait => $($tail)*);
 	};
 
-	(@tail $trait:ty => $property:ident read, $($tail:tt)*) => {
-		impl $property::CanGet for $trait {}
+	(@tail $trait:ty => $(#[$meta:meta])* $property:ident read, $($tail:tt)*) => {
+		$(#[$meta])* impl $property::CanGet for $trait {}
 		object_properties!(@tail $trait => $($tail)*);
 	};
 
@@ -326,8 +391,28 @@ macro_rules! object_properties {
 		object_properties!(@tail $trait => $($tail)*);
 	};
 
+	(@describe $trait:ty, $list:ident => ) => {};
+
+	(@describe $trait:ty, $list:ident => $capability:ident inherit, $($tail:tt)*) => {
+		$list.extend(<$trait as $capability>::group_properties());
+		object_properties!(@describe $trait, $list => $($tail)*);
+	};
+
+	(@describe $trait:ty, $list:ident => $(#[$meta:meta])* $property:ident $access:ident $(+ $more_access:ident)*, $($tail:tt)*) => {
+		$(#[$meta])* $list.extend($property::describe());
+		object_properties!(@describe $trait, $list => $($tail)*);
+	};
+
 	($trait:ty { $($tail:tt)* }) => {
 		object_properties!(@tail $trait => $($tail)*);
+
+		impl Introspect for $trait {
+			fn known_properties() -> Vec<PropertyDescription> {
+				let mut properties = Vec::new();
+				object_properties!(@describe $trait, properties => $($tail)*);
+				unique_properties(properties)
+			}
+		}
 	};
 }
 
@@ -359,9 +444,34 @@ pub trait Readable: AsProperties + Sized + Clone {
 	{
 		<P::ReturnType as Getter<Self, P>>::get_at(self, index)
 	}
+
+	fn get_all<P>(&self) -> Result<Vec<P::ReturnType>>
+	where
+		P: Named + Get,
+		P::ReturnType: ValueType + Sized + Getter<Self, P>,
+	{
+		let dimension = self.get_dimension::<P>()?;
+		(0..dimension)
+			.map(|index| self.get_at::<P>(index))
+			.collect()
+	}
+
+	fn get_dimension<P>(&self) -> Result<usize>
+	where
+		P: Named,
+	{
+		let mut c_int_out: Int = 0;
+		to_result! { suite_call!(propGetDimension in *self.suite(); self.handle(), P::name().as_ptr() as CharPtr, &mut c_int_out as *mut Int)
+		=> c_int_out as usize }
+	}
 }
 
 pub trait RawReadable: AsProperties + Sized + Clone {
+	// raw access is by name, so there are no statically known properties
+	fn group_properties() -> Vec<PropertyDescription> {
+		Vec::new()
+	}
+
 	#[inline]
 	fn get_raw<R, I>(&self, id: I) -> Result<R>
 	where
@@ -376,9 +486,8 @@ pub trait RawReadable: AsProperties + Sized + Clone {
 		I: StringId,
 		R: ValueType + Sized + RawGetter<Self>,
 	{
-		let c_buf = id.c_string()?;
-		let c_name = c_buf.as_ptr();
-		<R as RawGetter<Self>>::get_at(&self, c_name, index)
+		let c_name = id.c_name()?;
+		<R as RawGetter<Self>>::get_at(&self, c_name.as_ptr(), index)
 	}
 }
 
@@ -399,9 +508,34 @@ pub trait Writable: AsProperties + Sized + Clone {
 	{
 		<P::ValueType as Setter<Self, P>>::set_at(self, index, new_value)
 	}
+
+	/// Replaces all the values of a multi-valued property, stale trailing values are dropped
+	fn set_all<P, V>(&mut self, new_values: &[V]) -> Result<()>
+	where
+		P: Named + Set,
+		P::ValueType: ValueType + Setter<Self, P>,
+		V: Borrow<P::ValueType>,
+	{
+		self.reset::<P>()?;
+		for (index, new_value) in new_values.iter().enumerate() {
+			self.set_at::<P>(index, new_value.borrow())?;
+		}
+		Ok(())
+	}
+
+	fn reset<P>(&mut self) -> Result<()>
+	where
+		P: Named,
+	{
+		suite_fn!(propReset in *self.suite(); self.handle(), P::name().as_ptr() as CharPtr)
+	}
 }
 
 pub trait RawWritable: AsProperties + Sized + Clone {
+	fn group_properties() -> Vec<PropertyDescription> {
+		Vec::new()
+	}
+
 	#[inline]
 	fn set_raw<V, I>(&mut self, id: I, new_value: &V) -> Result<()>
 	where
@@ -416,9 +550,16 @@ pub trait RawWritable: AsProperties + Sized + Clone {
 		I: StringId,
 		V: ValueType + RawSetter<Self> + ?Sized + Debug,
 	{
-		let buf = id.c_string()?;
-		let c_name = buf.as_ptr();
-		<V as RawSetter<_>>::set_at(self, c_name, index, new_value)
+		let c_name = id.c_name()?;
+		<V as RawSetter<_>>::set_at(self, c_name.as_ptr(), index, new_value)
+	}
+
+	fn reset_raw<I>(&mut self, id: I) -> Result<()>
+	where
+		I: StringId,
+	{
+		let c_name = id.c_name()?;
+		suite_fn!(propReset in *self.suite(); self.handle(), c_name.as_ptr() as CharPtr)
 	}
 }
 
@@ -426,21 +567,41 @@ impl<R> Readable for R where R: AsProperties + Clone {}
 
 impl<W> Writable for W where W: AsProperties + ?Sized + Clone {}
 
+/// Names of properties, clips and parameters. Only &str needs a copy to
+/// append the nul, the other forms are passed to the host as they are
 pub trait StringId {
-	fn c_string(self) -> Result<CString>;
+	fn c_name(&self) -> Result<Cow<CStr>>;
 }
 
 impl StringId for &str {
-	fn c_string(self) -> Result<CString> {
-		Ok(CString::new(self)?)
+	fn c_name(&self) -> Result<Cow<CStr>> {
+		Ok(Cow::Owned(CString::new(*self)?))
 	}
 }
 
 impl StringId for &[u8] {
-	fn c_string(self) -> Result<CString> {
-		Ok(CStr::from_bytes_with_nul(self)
-			.map_err(|_| Error::InvalidNameEncoding)?
-			.to_owned())
+	fn c_name(&self) -> Result<Cow<CStr>> {
+		Ok(Cow::Borrowed(
+			CStr::from_bytes_with_nul(self).map_err(|_| Error::InvalidNameEncoding)?,
+		))
+	}
+}
+
+impl StringId for &String {
+	fn c_name(&self) -> Result<Cow<CStr>> {
+		Ok(Cow::Owned(CString::new(self.as_str())?))
+	}
+}
+
+impl StringId for &CStr {
+	fn c_name(&self) -> Result<Cow<CStr>> {
+		Ok(Cow::Borrowed(*self))
+	}
+}
+
+impl StringId for OfxName {
+	fn c_name(&self) -> Result<Cow<CStr>> {
+		Ok(Cow::Borrowed(self.as_c_str()))
 	}
 }
 
@@ -719,8 +880,9 @@ property! { kOfxPropLongLabel as LongLabel {
 	set_long_label(&str);
 }}
 
+// multi-valued, major version first
 property! { kOfxPropVersion as Version {
-	get_version() -> String;
+	get_version() -> Int;
 }}
 
 property! { kOfxPropVersionLabel as VersionLabel {
@@ -753,6 +915,17 @@ property! { kOfxPropHostOSHandle as HostOSHandle {
 	get_host_os_handle() -> VoidPtrMut;
 }}
 
+// the svg and the png file names, without extension
+property! { kOfxPropIcon as Icon {
+	get_icon() -> String as &seq;
+	set_icon(&seq [&str]);
+}}
+
+property! { kOfxPropInstanceData as InstanceData {
+	get_instance_data_ptr() -> VoidPtrMut;
+	set_instance_data_ptr(VoidPtr);
+}}
+
 property! { kOfxImageEffectHostPropIsBackground as IsBackground {
 	get_is_background() -> Bool;
 }}
@@ -781,6 +954,7 @@ property! { kOfxParamHostPropSupportsBooleanAnimation as SupportsBooleanAnimatio
 	get_supports_boolean_animation() -> Bool;
 }}
 
+#[cfg(feature = "parametric")]
 property! { kOfxParamHostPropSupportsParametricAnimation as SupportsParametricAnimation {
 	get_supports_parametric_animation() -> Bool;
 }}
@@ -807,6 +981,11 @@ property! { kOfxImageEffectPluginPropFieldRenderTwiceAlways as FieldRenderTwiceA
 	set_field_render_twice_always(Bool);
 }}
 
+property! { kOfxImageEffectPluginPropOverlayInteractV1 as OverlayInteractV1 {
+	get_overlay_interact_v1() -> VoidPtrMut;
+	set_overlay_interact_v1(VoidPtr);
+}}
+
 property! { kOfxImageEffectPluginPropSingleInstance as SingleInstance {
 	get_single_instance() -> Bool;
 	set_single_instance(Bool);
@@ -827,7 +1006,7 @@ property! { kOfxImageEffectPropContext as Context {
 }}
 
 property! { kOfxImageEffectPropComponents as Components {
-	get_components() -> CString as enum ImageComponent;
+	get_components() -> CString as enum ImageComponents;
 }}
 
 property! { kOfxImageEffectPropPixelDepth as PixelDepth {
@@ -896,25 +1075,29 @@ property! { kOfxImageEffectPropSetableFielding as SetableFielding {
 	get_setable_fielding() -> Bool;
 }}
 
-// TODO: allow multiple returns
 property! { kOfxImageEffectPropSupportedContexts as SupportedContexts {
-	get_supported_contexts() -> CString;
+	get_supported_contexts() -> CString as &[enum ImageEffectContext];
 	set_supported_contexts(&[u8] as &[enum ImageEffectContext]);
 }}
 
 property! { kOfxImageEffectPropSupportedPixelDepths as SupportedPixelDepths {
-	get_supported_pixel_depths() -> CString;
+	get_supported_pixel_depths() -> CString as &[enum BitDepth];
 	set_supported_pixel_depths(&[u8] as &[enum BitDepth]);
 }}
 
 property! { kOfxImageEffectPropSupportedComponents as SupportedComponents {
-	get_supported_components() -> CString;
+	get_supported_components() -> CString as &[enum ImageComponents];
 	set_supported_components(&[u8] as &[enum ImageComponent]);
 }}
 
 property! { kOfxImageEffectPropPreMultiplication as PreMultiplication {
-	get_pre_multiplication() -> Bool;
-	set_pre_multiplication(Bool);
+	get_premultiplication() -> CString as enum EImage;
+	set_premultiplication(&[u8] as enum EImage);
+}}
+
+property! { kOfxImageEffectFrameVarying as FrameVarying {
+	get_frame_varying() -> Bool;
+	set_frame_varying(Bool);
 }}
 
 property! { kOfxImageEffectPropRenderWindow as RenderWindow {
@@ -961,10 +1144,9 @@ property! { kOfxImageEffectPropTemporalClipAccess as TemporalClipAccess {
 	set_temporal_clip_access(Bool);
 }}
 
-// todo: return multiple strings
 property! { kOfxImageEffectPropClipPreferencesSlaveParam as ClipPreferencesSlaveParam {
-	get_clip_preferences_slave_param() -> String;
-	set_clip_preferences_slave_param(&str);
+	get_clip_preferences_slave_param() -> String as &seq;
+	set_clip_preferences_slave_param(&seq [&str]);
 }}
 
 property! { kOfxImageEffectPropSequentialRenderStatus as SequentialRenderStatus {
@@ -975,6 +1157,7 @@ property! { kOfxImageEffectPropInteractiveRenderStatus as InteractiveRenderStatu
 	get_interactive_render_status() -> Bool;
 }}
 
+#[cfg(feature = "opengl")]
 property! { kOfxImageEffectPropOpenGLRenderSupported as OpenGLRenderSupported {
 	get_opengl_render_supported() -> Bool;
 	set_opengl_render_supported(Bool);
@@ -984,6 +1167,15 @@ property! { kOfxImageEffectPropRenderQualityDraft as RenderQualityDraft {
 	get_render_quality_draft() -> Bool;
 }}
 
+property! { kOfxImageEffectPropPluginHandle as PluginHandle {
+	get_plugin_handle() -> VoidPtrMut;
+}}
+
+// deprecated since 1.4
+property! { kOfxImageEffectPropInAnalysis as InAnalysis {
+	get_in_analysis() -> Bool;
+}}
+
 property! { kOfxImageEffectInstancePropEffectDuration as EffectDuration {
 	get_effect_duration() -> Double;
 	set_effect_duration(Double);
@@ -999,7 +1191,7 @@ property! { kOfxImageClipPropConnected as Connected {
 }}
 
 property! { kOfxImageClipPropUnmappedComponents as UnmappedComponents {
-	get_unmapped_components() -> CString as enum ImageComponent;
+	get_unmapped_components() -> CString as enum ImageComponents;
 }}
 
 property! { kOfxImageClipPropUnmappedPixelDepth as UnmappedPixelDepth {
@@ -1031,6 +1223,66 @@ property! { kOfxImageClipPropContinuousSamples as ContinuousSamples {
 	set_continuous_samples(Bool);
 }}
 
+property! { kFnOfxImageEffectPropMultiPlanar as MultiPlanar {
+	get_multi_planar() -> Bool;
+	set_multi_planar(Bool);
+}}
+
+// 0: block unprocessed planes, 1: pass through from the clip returned by GetClipComponents,
+// 2: render every requested plane with one Render call per plane
+property! { kFnOfxImageEffectPropPassThroughComponents as PassThroughComponents {
+	get_pass_through_components() -> Int;
+	set_pass_through_components(Int);
+}}
+
+property! { kOfxImageEffectPropRenderAllPlanes as RenderAllPlanes {
+	get_render_all_planes() -> Bool;
+	set_render_all_planes(Bool);
+}}
+
+property! { kFnOfxImageEffectPropComponentsPresent as ComponentsPresent {
+	get_components_present() -> CString as enum ImagePlane;
+}}
+
+property! { kOfxImageEffectPropRenderPlanes as RenderPlanes {
+	get_render_plane() -> CString as enum ImagePlane;
+}}
+
+property! { kOfxImageEffectPropIdentityPlane as IdentityPlane {
+	get_identity_plane() -> String;
+	set_identity_plane(&str);
+}}
+
+property! { kFnOfxImageEffectPropPassThroughClip as PassThroughClip {
+	get_pass_through_clip() -> String;
+	set_pass_through_clip(&str);
+}}
+
+property! { kFnOfxImageEffectPropPassThroughTime as PassThroughTime {
+	get_pass_through_time() -> Double;
+	set_pass_through_time(Double);
+}}
+
+property! { kFnOfxImageEffectPropPassThroughView as PassThroughView {
+	get_pass_through_view() -> Int;
+	set_pass_through_view(Int);
+}}
+
+property! { kFnOfxImageEffectPropViewAware as ViewAware {
+	get_view_aware() -> Bool;
+	set_view_aware(Bool);
+}}
+
+// 0: all views differ, 1: only pass through planes are the same, 2: all views are the same
+property! { kFnOfxImageEffectPropViewInvariance as ViewInvariance {
+	get_view_invariance() -> Int;
+	set_view_invariance(Int);
+}}
+
+property! { kFnOfxImageEffectPropView as View {
+	get_view() -> Int;
+}}
+
 property! { kOfxImagePropRowBytes as RowBytes {
 	get_row_bytes() -> Int;
 }}
@@ -1067,6 +1319,7 @@ property! { kOfxParamPropEnabled as Enabled {
 
 property! { kOfxPropParamSetNeedsSyncing as NeedsSyncing {
 	get_needs_syncing() -> Bool;
+	set_needs_syncing(Bool);
 }}
 
 property! { kOfxParamPropHint as Hint {
@@ -1084,13 +1337,156 @@ property! { kOfxParamPropScriptName as ScriptName {
 	set_script_name(&str);
 }}
 
+property! { kOfxParamPropType as ParameterType {
+	get_param_type() -> CString as enum EParamType;
+}}
+
+property! { kOfxParamPropSecret as Secret {
+	get_secret() -> Bool;
+	set_secret(Bool);
+}}
+
+property! { kOfxParamPropCanUndo as CanUndo {
+	get_can_undo() -> Bool;
+	set_can_undo(Bool);
+}}
+
+property! { kOfxParamPropDataPtr as DataPtr {
+	get_data_ptr() -> VoidPtrMut;
+	set_data_ptr(VoidPtr);
+}}
+
+property! { kOfxParamPropInteractV1 as InteractV1 {
+	get_interact_v1() -> VoidPtrMut;
+	set_interact_v1(VoidPtr);
+}}
+
+property! { kOfxParamPropInteractSize as InteractSize {
+	get_interact_size() -> PointD;
+	set_interact_size(PointD);
+}}
+
+property! { kOfxParamPropInteractSizeAspect as InteractSizeAspect {
+	get_interact_size_aspect() -> Double;
+	set_interact_size_aspect(Double);
+}}
+
+property! { kOfxParamPropInteractMinimumSize as InteractMinimumSize {
+	get_interact_minimum_size() -> PointI;
+	set_interact_minimum_size(PointI);
+}}
+
+property! { kOfxParamPropInteractPreferedSize as InteractPreferedSize {
+	get_interact_prefered_size() -> PointI;
+	set_interact_prefered_size(PointI);
+}}
+
+property! { kOfxParamPropHasHostOverlayHandle as HasHostOverlayHandle {
+	get_has_host_overlay_handle() -> Bool;
+}}
+
+property! { kOfxParamPropUseHostOverlayHandle as UseHostOverlayHandle {
+	get_use_host_overlay_handle() -> Bool;
+	set_use_host_overlay_handle(Bool);
+}}
+
+property! { kOfxParamPropAnimates as Animates {
+	get_animates() -> Bool;
+	set_animates(Bool);
+}}
+
+property! { kOfxParamPropIsAnimating as IsAnimating {
+	get_is_animating() -> Bool;
+}}
+
+property! { kOfxParamPropIsAutoKeying as IsAutoKeying {
+	get_is_auto_keying() -> Bool;
+}}
+
+property! { kOfxParamPropPersistent as Persistent {
+	get_persistent() -> Bool;
+	set_persistent(Bool);
+}}
+
+property! { kOfxParamPropEvaluateOnChange as EvaluateOnChange {
+	get_evaluate_on_change() -> Bool;
+	set_evaluate_on_change(Bool);
+}}
+
+// deprecated since 1.4
+property! { kOfxParamPropPluginMayWrite as PluginMayWrite {
+	get_plugin_may_write() -> Bool;
+	set_plugin_may_write(Bool);
+}}
+
+property! { kOfxParamPropCacheInvalidation as CacheInvalidation {
+	get_cache_invalidation() -> CString as enum ParamInvalidate;
+	set_cache_invalidation(&[u8] as enum ParamInvalidate);
+}}
+
+property! { kOfxParamPropChoiceOption as ChoiceOption {
+	get_choice_option() -> String as &seq;
+	set_choice_option(&seq [&str]);
+}}
+
+property! { kOfxParamPropStringMode as StringMode {
+	get_string_mode() -> CString as enum ParamString;
+	set_string_mode(&[u8] as enum ParamString);
+}}
+
+property! { kOfxParamPropStringFilePathExists as StringFilePathExists {
+	get_string_file_path_exists() -> Bool;
+	set_string_file_path_exists(Bool);
+}}
+
+property! { kOfxParamPropCustomInterpCallbackV1 as CustomInterpCallbackV1 {
+	get_custom_interp_callback_v1() -> VoidPtrMut;
+	set_custom_interp_callback_v1(VoidPtr);
+}}
+
+property! { kOfxParamPropGroupOpen as GroupOpen {
+	get_group_open() -> Bool;
+	set_group_open(Bool);
+}}
+
+property! { kOfxPluginPropParamPageOrder as ParamPageOrder {
+	get_param_page_order() -> String as &seq;
+	set_param_page_order(&seq [&str]);
+}}
+
 property_group! { CommonParameters {
 	Type				read,
+	ParameterType		read,
+	Name				read,
 	Label				read+write,
+	ShortLabel			read+write,
+	LongLabel			read+write,
 	Hint				read+write,
 	Parent				read+write,
 	ScriptName			read+write,
 	Enabled				read+write,
+	Secret				read+write,
+	CanUndo				read+write,
+	DataPtr				read+write,
+	Icon				read+write,
+}}
+
+// all the parameters holding a value, that is all but groups, pages and push buttons
+property_group! { ValueParameters {
+	InteractV1				read+write,
+	InteractSize			read+write,
+	InteractSizeAspect		read+write,
+	InteractMinimumSize		read+write,
+	InteractPreferedSize	read+write,
+	HasHostOverlayHandle	read,
+	UseHostOverlayHandle	read+write,
+	Animates				read+write,
+	IsAnimating				read,
+	IsAutoKeying			read,
+	Persistent				read+write,
+	EvaluateOnChange		read+write,
+	PluginMayWrite			read+write,
+	CacheInvalidation		read+write,
 }}
 
 pub mod double {
@@ -1099,6 +1495,21 @@ pub mod double {
 	property_assign_name!(kOfxParamPropDefault as Default: Double);
 	property_assign_name!(kOfxParamPropDisplayMax as DisplayMax: Double);
 	property_assign_name!(kOfxParamPropDisplayMin as DisplayMin: Double);
+	property_assign_name!(kOfxParamPropMax as Max: Double);
+	property_assign_name!(kOfxParamPropMin as Min: Double);
+	property_assign_name!(kOfxParamPropIncrement as Increment: Double);
+	property_assign_name!(kOfxParamPropDigits as Digits: Int);
+	property_assign_name!(kOfxParamPropShowTimeMarker as ShowTimeMarker: Bool);
+	property_assign_name!(kOfxParamPropDefaultCoordinateSystem as DefaultCoordinateSystem: (&[u8]) -> CString);
+}
+
+pub mod integer {
+	use super::*;
+	property_assign_name!(kOfxParamPropDefault as Default: Int);
+	property_assign_name!(kOfxParamPropDisplayMax as DisplayMax: Int);
+	property_assign_name!(kOfxParamPropDisplayMin as DisplayMin: Int);
+	property_assign_name!(kOfxParamPropMax as Max: Int);
+	property_assign_name!(kOfxParamPropMin as Min: Int);
 }
 
 pub mod boolean {
@@ -1106,6 +1517,18 @@ pub mod boolean {
 	property_assign_name!(kOfxParamPropDefault as Default: Bool);
 }
 
+// choices default to the index of the option
+pub mod choice {
+	use super::*;
+	property_assign_name!(kOfxParamPropDefault as Default: Int);
+}
+
+// string and custom parameters
+pub mod string {
+	use super::*;
+	property_assign_name!(kOfxParamPropDefault as Default: (&str) -> String);
+}
+
 pub mod page {
 	use super::*;
 	property_assign_name!(kOfxParamPropPageChild as Child: (&str) -> String);
@@ -1115,6 +1538,9 @@ pub mod page {
 pub mod Children {
 	use super::*;
 	property_define_setter_trait!(CanSet => set_children, page::Child, &seq[&str]);
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![PropertyDescription::of::<page::Child>()]
+	}
 }
 pub use Children::CanSet as CanSetChildren;
 
@@ -1129,6 +1555,13 @@ pub mod Labels {
 			Ok(())
 		}
 	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![
+			PropertyDescription::of::<Label::Property>(),
+			PropertyDescription::of::<ShortLabel::Property>(),
+			PropertyDescription::of::<LongLabel::Property>(),
+		]
+	}
 }
 
 impl<T> Labels::CanSet for T where T: Label::CanSet + ShortLabel::CanSet + LongLabel::CanSet {}
@@ -1152,22 +1585,135 @@ pub mod DoubleParams {
 		property_define_setter_trait!(set_default, double::Default);
 		property_define_setter_trait!(set_display_max, double::DisplayMax);
 		property_define_setter_trait!(set_display_min, double::DisplayMin);
+		property_define_setter_trait!(set_max, double::Max);
+		property_define_setter_trait!(set_min, double::Min);
+		property_define_setter_trait!(set_increment, double::Increment);
+		property_define_setter_trait!(set_digits, double::Digits);
+		property_define_setter_trait!(set_show_time_marker, double::ShowTimeMarker);
+		property_define_setter_trait!(set_default_coordinate_system, double::DefaultCoordinateSystem, enum ParamCoordinates);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![
+			PropertyDescription::of::<double::DoubleType>(),
+			PropertyDescription::of::<double::Default>(),
+			PropertyDescription::of::<double::DisplayMax>(),
+			PropertyDescription::of::<double::DisplayMin>(),
+			PropertyDescription::of::<double::Max>(),
+			PropertyDescription::of::<double::Min>(),
+			PropertyDescription::of::<double::Increment>(),
+			PropertyDescription::of::<double::Digits>(),
+			PropertyDescription::of::<double::ShowTimeMarker>(),
+			PropertyDescription::of::<double::DefaultCoordinateSystem>(),
+		]
 	}
 }
 
 pub use DoubleParams::CanSet as CanSetDoubleParams;
 
+#[allow(non_snake_case)]
+pub mod IntParams {
+	use super::*;
+	pub trait CanSet: Writable {
+		property_define_setter_trait!(set_default, integer::Default);
+		property_define_setter_trait!(set_display_max, integer::DisplayMax);
+		property_define_setter_trait!(set_display_min, integer::DisplayMin);
+		property_define_setter_trait!(set_max, integer::Max);
+		property_define_setter_trait!(set_min, integer::Min);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![
+			PropertyDescription::of::<integer::Default>(),
+			PropertyDescription::of::<integer::DisplayMax>(),
+			PropertyDescription::of::<integer::DisplayMin>(),
+			PropertyDescription::of::<integer::Max>(),
+			PropertyDescription::of::<integer::Min>(),
+		]
+	}
+}
+
+pub use IntParams::CanSet as CanSetIntParams;
+
+// the limits can still change once the parameter is instantiated
+#[allow(non_snake_case)]
+pub mod DoubleLimits {
+	use super::*;
+	pub trait CanSet: Writable {
+		property_define_setter_trait!(set_display_max, double::DisplayMax);
+		property_define_setter_trait!(set_display_min, double::DisplayMin);
+		property_define_setter_trait!(set_max, double::Max);
+		property_define_setter_trait!(set_min, double::Min);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![
+			PropertyDescription::of::<double::DisplayMax>(),
+			PropertyDescription::of::<double::DisplayMin>(),
+			PropertyDescription::of::<double::Max>(),
+			PropertyDescription::of::<double::Min>(),
+		]
+	}
+}
+
+pub use DoubleLimits::CanSet as CanSetDoubleLimits;
+
+#[allow(non_snake_case)]
+pub mod IntLimits {
+	use super::*;
+	pub trait CanSet: Writable {
+		property_define_setter_trait!(set_display_max, integer::DisplayMax);
+		property_define_setter_trait!(set_display_min, integer::DisplayMin);
+		property_define_setter_trait!(set_max, integer::Max);
+		property_define_setter_trait!(set_min, integer::Min);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![
+			PropertyDescription::of::<integer::DisplayMax>(),
+			PropertyDescription::of::<integer::DisplayMin>(),
+			PropertyDescription::of::<integer::Max>(),
+			PropertyDescription::of::<integer::Min>(),
+		]
+	}
+}
+
+pub use IntLimits::CanSet as CanSetIntLimits;
+
 #[allow(non_snake_case)]
 pub mod BooleanParams {
 	use super::*;
 	pub trait CanSet: Writable {
 		property_define_setter_trait!(set_default, boolean::Default);
 	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![PropertyDescription::of::<boolean::Default>()]
+	}
 }
 
 pub use BooleanParams::CanSet as CanSetBooleanParams;
 
-impl<T> CommonParameters for ParamHandle<T> where T: ParamHandleValue + Clone {}
+#[allow(non_snake_case)]
+pub mod ChoiceParams {
+	use super::*;
+	pub trait CanSet: Writable {
+		property_define_setter_trait!(set_default, choice::Default);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![PropertyDescription::of::<choice::Default>()]
+	}
+}
+
+pub use ChoiceParams::CanSet as CanSetChoiceParams;
+
+#[allow(non_snake_case)]
+pub mod StringParams {
+	use super::*;
+	pub trait CanSet: Writable {
+		property_define_setter_trait!(set_default, &string::Default);
+	}
+	pub fn describe() -> Vec<PropertyDescription> {
+		vec![PropertyDescription::of::<string::Default>()]
+	}
+}
+
+pub use StringParams::CanSet as CanSetStringParams;
 
 // https://openfx.readthedocs.io/en/doc/Reference/ofxPropertiesByObject.html#properties-on-an-effect-descriptor
 object_properties! { ImageEffectHost {
@@ -1175,6 +1721,7 @@ object_properties! { ImageEffectHost {
 	Label						read,
 	Version						read,
 	VersionLabel				read,
+	APIVersion					read,
 	IsBackground				read,
 	SupportsOverlays			read,
 	SupportsMultiResolution		read,
@@ -1182,6 +1729,7 @@ object_properties! { ImageEffectHost {
 	TemporalClipAccess			read,
 	SupportedComponents			read,
 	SupportedContexts			read,
+	SupportedPixelDepths		read,
 	SupportsMultipleClipDepths	read,
 	SupportsMultipleClipPARs	read,
 	SetableFrameRate			read,
@@ -1195,14 +1743,18 @@ object_properties! { ImageEffectHost {
 	MaxPages					read,
 	PageRowColumnCount			read,
 	HostOSHandle				read,
+	#[cfg(feature = "parametric")]
 	SupportsParametricAnimation	read,
 	SequentialRender			read,
+	#[cfg(feature = "opengl")]
 	OpenGLRenderSupported		read,
 	RenderQualityDraft			read,
 	NativeOrigin				read,
+	MultiPlanar					read,
+	RawReadable					inherit,
 }}
 
-// TODO: canset should be only exposed in the "Describe" action
+// only available in the describe actions
 object_properties! { EffectDescriptor {
 	Type						read,
 	Label						read+write,
@@ -1216,8 +1768,7 @@ object_properties! { EffectDescriptor {
 	SingleInstance				read+write,
 	RenderThreadSafety			read+write,
 	HostFrameThreading			read+write,
-//	TODO: missing yet
-//	OverlayInteractV1			read+write,
+	OverlayInteractV1			read+write,
 	SupportsMultiResolution		read+write,
 	SupportsTiles				read+write,
 	TemporalClipAccess			read+write,
@@ -1225,9 +1776,15 @@ object_properties! { EffectDescriptor {
 	FieldRenderTwiceAlways		read,
 	SupportsMultipleClipDepths	read+write,
 	SupportsMultipleClipPARs	read+write,
+	#[cfg(feature = "opengl")]
 	OpenGLRenderSupported		read+write,
 	ClipPreferencesSlaveParam	read+write,
 	FilePath					read,
+	MultiPlanar					read+write,
+	PassThroughComponents		read+write,
+	RenderAllPlanes				read+write,
+	ViewAware					read+write,
+	ViewInvariance				read+write,
 	// convenience extras
 	Labels						write,
 }}
@@ -1245,10 +1802,15 @@ object_properties! { EffectInstance {
 	SequentialRender			read+write,
 	SupportsTiles				read+write,
 	SupportsMultiResolution		read+write,
+	#[cfg(feature = "opengl")]
 	OpenGLRenderSupported		read+write,
 	FrameRate					read,
 	SupportedPixelDepths		read+write,
 	IsInteractive				read,
+	RenderAllPlanes				read,
+	InstanceData				read+write,
+	PluginHandle				read,
+	InAnalysis					read,
 }}
 
 // Clip Descriptor
@@ -1292,6 +1854,28 @@ object_properties! { ClipInstance {
 	UnmappedFrameRange			read,
 	UnmappedFrameRate			read,
 	ContinuousSamples			read,
+	ComponentsPresent			read,
+}}
+
+#[cfg(feature = "camera")]
+object_properties! { CameraDescriptor {
+	Type						read,
+	Name						read,
+	Label						read+write,
+	ShortLabel					read+write,
+	LongLabel					read+write,
+	Optional					read+write,
+}}
+
+#[cfg(feature = "camera")]
+object_properties! { CameraInstance {
+	Type						read,
+	Name						read,
+	Label						read,
+	ShortLabel					read,
+	LongLabel					read,
+	Optional					read,
+	Connected					read,
 }}
 
 object_properties! { Image {
@@ -1311,14 +1895,48 @@ object_properties! { Image {
 
 object_properties! { ParamDouble {
 	CommonParameters			inherit,
+	ValueParameters				inherit,
 	DoubleParams				write,
 }}
 
+object_properties! { ParamInt {
+	CommonParameters			inherit,
+	ValueParameters				inherit,
+	IntParams					write,
+}}
+
 object_properties! { ParamBoolean {
 	CommonParameters			inherit,
+	ValueParameters				inherit,
 	BooleanParams				write,
 }}
 
+object_properties! { ParamChoice {
+	CommonParameters			inherit,
+	ValueParameters				inherit,
+	ChoiceParams				write,
+	ChoiceOption				read+write,
+}}
+
+object_properties! { ParamText {
+	CommonParameters			inherit,
+	ValueParameters				inherit,
+	StringParams				write,
+	StringMode					read+write,
+	StringFilePathExists		read+write,
+}}
+
+object_properties! { ParamCustom {
+	CommonParameters			inherit,
+	ValueParameters				inherit,
+	StringParams				write,
+	CustomInterpCallbackV1		read+write,
+}}
+
+object_properties! { ParamPushButton {
+	CommonParameters			inherit,
+}}
+
 object_properties! { ParamPage {
 	CommonParameters			inherit,
 	Children					write,
@@ -1326,11 +1944,66 @@ object_properties! { ParamPage {
 
 object_properties! { ParamGroup {
 	CommonParameters			inherit,
+	GroupOpen					read+write,
 }}
 
 object_properties! { ParameterSet {
-	NeedsSyncing				read,
-}}
+	ParamPageOrder				read+write,
+}}
+
+object_properties! { ParameterSetInstance {
+	NeedsSyncing				read+write,
+	ParamPageOrder				read,
+}}
+
+// the descriptor parameters above only exist in the describe actions, most of
+// their properties are read only once instantiated
+macro_rules! param_instance_properties {
+	($($param:ty { $($tail:tt)* })*) => {
+		$(object_properties! { $param {
+			Type						read,
+			ParameterType				read,
+			Name						read,
+			Label						read,
+			ShortLabel					read,
+			LongLabel					read,
+			Hint						read+write,
+			Parent						read,
+			ScriptName					read,
+			Enabled						read+write,
+			Secret						read+write,
+			CanUndo						read,
+			DataPtr						read+write,
+			Icon						read,
+			InteractV1					read,
+			InteractSize				read,
+			InteractSizeAspect			read,
+			InteractMinimumSize			read,
+			InteractPreferedSize		read,
+			HasHostOverlayHandle		read,
+			UseHostOverlayHandle		read,
+			Animates					read,
+			IsAnimating					read,
+			IsAutoKeying				read,
+			Persistent					read,
+			EvaluateOnChange			read+write,
+			PluginMayWrite				read,
+			CacheInvalidation			read,
+			$($tail)*
+		}})
+		*
+	};
+}
+
+param_instance_properties! {
+	ParamHandle<Double> {
+		DoubleLimits			write,
+	}
+	ParamHandle<Int> {
+		IntLimits				write,
+	}
+	ParamHandle<Bool> {}
+}
 
 object_properties! { DescribeInContextInArgs {
 	Context						read,
@@ -1341,11 +2014,13 @@ object_properties! { IsIdentityInArgs {
 	FieldToRender				read,
 	RenderWindow				read,
 	RenderScale					read,
+	IdentityPlane				read,
 }}
 
 object_properties! { IsIdentityOutArgs {
 	Name						write,
 	Time						write,
+	IdentityPlane				write,
 }}
 
 object_properties! { GetRegionOfDefinitionInArgs {
@@ -1358,6 +2033,8 @@ object_properties! { GetRegionOfDefinitionOutArgs {
 }}
 
 object_properties! { GetRegionsOfInterestInArgs {
+	Time						read,
+	RenderScale					read,
 	RegionOfInterest			read,
 }}
 
@@ -1368,6 +2045,11 @@ object_properties! { GetRegionsOfInterestOutArgs {
 
 object_properties! { GetClipPreferencesOutArgs {
 	RawWritable					inherit,
+	FrameRate					write,
+	FieldOrder					write,
+	PreMultiplication			write,
+	ContinuousSamples			write,
+	FrameVarying				write,
 }}
 
 object_properties! { InstanceChangedInArgs {
@@ -1394,6 +2076,8 @@ object_properties! { RenderInArgs {
 	SequentialRenderStatus		read,
 	InteractiveRenderStatus		read,
 	RenderQualityDraft			read,
+	RenderPlanes				read,
+	View						read,
 }}
 
 object_properties! { BeginSequenceRenderInArgs {
@@ -1419,3 +2103,14 @@ object_properties! { EndSequenceRenderInArgs {
 object_properties! { GetTimeDomainOutArgs {
 	FrameRange					write,
 }}
+
+object_properties! { GetClipComponentsInArgs {
+	Time						read,
+}}
+
+object_properties! { GetClipComponentsOutArgs {
+	RawWritable					inherit,
+	PassThroughClip				write,
+	PassThroughTime				write,
+	PassThroughView				write,
+}}
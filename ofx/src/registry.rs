@@ -1,8 +1,10 @@
 use action::*;
+use logging::*;
 use ofx_sys::*;
 use plugin::*;
 use result::*;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use types::*;
 
 #[derive(Default)]
@@ -62,11 +64,11 @@ impl Registry {
 		&self.plugins[index as usize].ofx_plugin()
 	}
 
-	pub fn dispatch(&mut self, plugin_module: &str, message: RawMessage) -> Result<Int> {
-		info!("{}:{:?}", plugin_module, message);
+	pub fn dispatch(&self, plugin_module: &str, message: RawMessage) -> Result<Int> {
+		info!(target: plugin_module, "{:?}", message);
 		let found_plugin = self.plugin_modules.get(plugin_module).cloned();
 		if let Some(plugin_index) = found_plugin {
-			let plugin = self.get_plugin_mut(plugin_index);
+			let plugin = self.get_plugin(plugin_index);
 			plugin.dispatch(message)
 		} else {
 			Err(Error::PluginNotFound)
@@ -75,14 +77,19 @@ impl Registry {
 }
 
 pub unsafe fn set_host_for_plugin(plugin_module: &str, host: *mut OfxHost) {
-	unsafe {
-		get_registry_mut()
-			.dispatch(plugin_module, RawMessage::SetHost { host: *host })
-			.ok();
+	if let Some(registry) = get_registry() {
+		unsafe {
+			registry
+				.dispatch(plugin_module, RawMessage::SetHost { host: *host })
+				.ok();
+		}
+	} else {
+		error!(target: plugin_module, "Host set before the plugin registry was built");
 	}
 }
 
-static mut _GLOBAL_REGISTRY: Option<Registry> = None;
+// built once on the first call from the host, read only afterwards
+static GLOBAL_REGISTRY: OnceLock<Registry> = OnceLock::new();
 
 pub fn main_entry_for_plugin(
 	plugin_module: &str,
@@ -91,8 +98,8 @@ pub fn main_entry_for_plugin(
 	in_args: OfxPropertySetHandle,
 	out_args: OfxPropertySetHandle,
 ) -> Int {
-	unsafe {
-		get_registry_mut()
+	match get_registry() {
+		Some(registry) => registry
 			.dispatch(
 				plugin_module,
 				RawMessage::MainEntry {
@@ -102,8 +109,12 @@ pub fn main_entry_for_plugin(
 					out_args,
 				},
 			)
-			.ok()
-			.unwrap_or(-1)
+			.unwrap_or_else(Error::status),
+		// never panic across the C boundary
+		None => {
+			error!(target: plugin_module, "Action received before the plugin registry was built");
+			eOfxStatus_Failed
+		}
 	}
 }
 
@@ -111,41 +122,20 @@ pub fn init_registry<F>(init_function: F)
 where
 	F: Fn(&mut Registry),
 {
-	unsafe {
-		if _GLOBAL_REGISTRY.is_none() {
-			use log4rs::append::console::*;
-			use log4rs::config::*;
-
-			if let Err(e) = log4rs::init_file("ofx_log4rs.yaml", Default::default()) {
-				let config = Config::builder()
-					.appender(Appender::builder().build(
-						"stdout".to_string(),
-						Box::new(ConsoleAppender::builder().build()),
-					))
-					.logger(Logger::builder().build("ofx".to_string(), log::LevelFilter::Warn))
-					.build(
-						Root::builder()
-							.appender("stdout".to_string())
-							.build(log::LevelFilter::Error),
-					);
-				log4rs::init_config(config.unwrap()).unwrap();
-			}
-			let mut registry = Registry::new();
-			init_function(&mut registry);
-			for plugin in &registry.plugins {
-				info!("Registered plugin {}", plugin);
-			}
-			_GLOBAL_REGISTRY = Some(registry);
+	GLOBAL_REGISTRY.get_or_init(|| {
+		init_logging();
+		let mut registry = Registry::new();
+		init_function(&mut registry);
+		for plugin in &registry.plugins {
+			info!("Registered plugin {}", plugin);
 		}
-	}
+		registry
+	});
 }
 
-fn get_registry_mut() -> &'static mut Registry {
-	unsafe { _GLOBAL_REGISTRY.as_mut().unwrap() }
-}
-
-pub fn get_registry() -> &'static Registry {
-	unsafe { _GLOBAL_REGISTRY.as_ref().unwrap() }
+/// None until init_registry has run
+pub fn get_registry() -> Option<&'static Registry> {
+	GLOBAL_REGISTRY.get()
 }
 
 #[macro_export]
@@ -211,13 +201,18 @@ macro_rules! build_plugin_registry {
 		#[no_mangle]
 		pub extern "C" fn OfxGetNumberOfPlugins() -> Int {
 			init();
-			get_registry().count()
+			get_registry().map_or(0, |registry| registry.count())
 		}
 
 		#[no_mangle]
 		pub extern "C" fn OfxGetPlugin(nth: Int) -> *const OfxPlugin {
 			init();
-			get_registry().ofx_plugin(nth) as *const OfxPlugin
+			match get_registry() {
+				Some(registry) if nth >= 0 && nth < registry.count() => {
+					registry.ofx_plugin(nth) as *const OfxPlugin
+				}
+				_ => std::ptr::null(),
+			}
 		}
 
 		pub fn show_plugins() -> Vec<String> {
@@ -226,12 +221,13 @@ macro_rules! build_plugin_registry {
 				for i in 0..n {
 					OfxGetPlugin(i);
 				}
-				(0..n)
-					.map(|i| {
-						let plugin = get_registry().get_plugin(i as usize);
-						format!("{}", plugin)
+				get_registry()
+					.map(|registry| {
+						(0..n)
+							.map(|i| format!("{}", registry.get_plugin(i as usize)))
+							.collect()
 					})
-					.collect()
+					.unwrap_or_default()
 			}
 		}
 	};
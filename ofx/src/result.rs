@@ -24,7 +24,15 @@ pub enum Error {
 	PropertyIndexOutOfBounds,
 	HostNotReady,
 	EnumNotFound,
+	UnknownClip,
+	ClipNotConnected,
 	SuiteNotInitialized,
+	MissingHostFeature,
+	OutOfMemory,
+	WorkerPanicked,
+	LockPoisoned,
+	MutexReentered,
+	LoggerAlreadySet,
 	Unimplemented,
 	UnknownError,
 }
@@ -40,11 +48,22 @@ impl From<OfxStatus> for Error {
 			ofx_sys::eOfxStatus_ErrBadHandle => Error::InvalidHandle,
 			ofx_sys::eOfxStatus_ErrBadIndex => Error::InvalidIndex,
 			ofx_sys::eOfxStatus_ErrValue => Error::InvalidValue,
+			ofx_sys::eOfxStatus_ErrMissingHostFeature => Error::MissingHostFeature,
 			_ => Error::UnknownError,
 		}
 	}
 }
 
+impl Error {
+	/// The status returned to the host when an action fails with this error
+	pub fn status(self) -> OfxStatus {
+		match self {
+			Error::MissingHostFeature => eOfxStatus_ErrMissingHostFeature,
+			_ => -1,
+		}
+	}
+}
+
 macro_rules! to_result {
 	{$ofx_status:expr => $result:expr} => {
 		match $ofx_status {
@@ -81,6 +100,12 @@ impl From<std::str::Utf8Error> for Error {
 	}
 }
 
+impl<T> From<std::sync::PoisonError<T>> for Error {
+	fn from(_src: std::sync::PoisonError<T>) -> Error {
+		Error::LockPoisoned
+	}
+}
+
 impl fmt::Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "Openfx error")
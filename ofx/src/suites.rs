@@ -1,22 +1,103 @@
 use ofx_sys::*;
 use result::*;
 use std::borrow::Borrow;
-use std::rc::Rc;
+use std::sync::Arc;
+use types::*;
+
+/// The suites a plugin can depend on. ImageEffect, Property and Parameter are
+/// always required, the others are optional unless declared in
+/// Execute::required_suites
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuiteKind {
+	ImageEffect,
+	Property,
+	Parameter,
+	Memory,
+	MultiThread,
+	Message,
+	Progress,
+	TimeLine,
+	ImageEffectPlane,
+	#[cfg(feature = "parametric")]
+	ParametricParameter,
+	#[cfg(feature = "opengl")]
+	ImageEffectOpenGLRender,
+	#[cfg(feature = "camera")]
+	Camera,
+}
+
+/// Features which depend on the version of a suite negotiated with the host
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuiteFeature {
+	/// setPersistentMessage and clearPersistentMessage
+	PersistentMessages,
+	/// progress bars with a message id
+	ProgressMessageId,
+	/// image planes and regions of definition per view
+	ImagePlaneViews,
+}
+
+impl SuiteFeature {
+	/// The suite and the minimum version providing the feature
+	pub fn requires(self) -> (SuiteKind, Int) {
+		match self {
+			SuiteFeature::PersistentMessages => (SuiteKind::Message, 2),
+			SuiteFeature::ProgressMessageId => (SuiteKind::Progress, 2),
+			SuiteFeature::ImagePlaneViews => (SuiteKind::ImageEffectPlane, 2),
+		}
+	}
+}
+
+/// The newest version of the progress suite offered by the host
+#[derive(Clone)]
+pub enum ProgressSuite {
+	V1(Arc<OfxProgressSuiteV1>),
+	V2(Arc<OfxProgressSuiteV2>),
+}
+
+/// The newest version of the image plane suite offered by the host
+#[derive(Clone)]
+pub enum ImageEffectPlaneSuite {
+	V1(Arc<FnOfxImageEffectPlaneSuiteV1>),
+	V2(Arc<FnOfxImageEffectPlaneSuiteV2>),
+}
+
+impl ProgressSuite {
+	pub fn version(&self) -> Int {
+		match *self {
+			ProgressSuite::V1(_) => 1,
+			ProgressSuite::V2(_) => 2,
+		}
+	}
+}
+
+impl ImageEffectPlaneSuite {
+	pub fn version(&self) -> Int {
+		match *self {
+			ImageEffectPlaneSuite::V1(_) => 1,
+			ImageEffectPlaneSuite::V2(_) => 2,
+		}
+	}
+}
 
 #[derive(Clone)]
 pub struct Suites {
-	image_effect: Rc<OfxImageEffectSuiteV1>,
-	property: Rc<OfxPropertySuiteV1>,
-	parameter: Rc<OfxParameterSuiteV1>,
-	memory: Rc<OfxMemorySuiteV1>,
-	pub(crate) multi_thread: Rc<OfxMultiThreadSuiteV1>,
-	message: Rc<OfxMessageSuiteV1>,
-	message_v2: Option<Rc<OfxMessageSuiteV2>>,
-	progress: Rc<OfxProgressSuiteV1>,
-	progress_v2: Option<Rc<OfxProgressSuiteV2>>,
-	time_line: Rc<OfxTimeLineSuiteV1>,
-	parametric_parameter: Option<Rc<OfxParametricParameterSuiteV1>>,
-	image_effect_opengl_render: Option<Rc<OfxImageEffectOpenGLRenderSuiteV1>>,
+	image_effect: Arc<OfxImageEffectSuiteV1>,
+	property: Arc<OfxPropertySuiteV1>,
+	parameter: Arc<OfxParameterSuiteV1>,
+	memory: Option<Arc<OfxMemorySuiteV1>>,
+	multi_thread: Option<Arc<OfxMultiThreadSuiteV1>>,
+	message: Option<Arc<OfxMessageSuiteV1>>,
+	message_v2: Option<Arc<OfxMessageSuiteV2>>,
+	progress: Option<ProgressSuite>,
+	time_line: Option<Arc<OfxTimeLineSuiteV1>>,
+	image_effect_plane: Option<ImageEffectPlaneSuite>,
+	#[cfg(feature = "parametric")]
+	parametric_parameter: Option<Arc<OfxParametricParameterSuiteV1>>,
+	#[cfg(feature = "opengl")]
+	image_effect_opengl_render: Option<Arc<OfxImageEffectOpenGLRenderSuiteV1>>,
+	#[cfg(feature = "camera")]
+	camera: Option<Arc<NukeOfxCameraSuiteV1>>,
 }
 
 macro_rules! suite_call {
@@ -37,41 +118,209 @@ impl Suites {
 		image_effect: OfxImageEffectSuiteV1,
 		property: OfxPropertySuiteV1,
 		parameter: OfxParameterSuiteV1,
-		memory: OfxMemorySuiteV1,
-		multi_thread: OfxMultiThreadSuiteV1,
-		message: OfxMessageSuiteV1,
+		memory: Option<OfxMemorySuiteV1>,
+		multi_thread: Option<OfxMultiThreadSuiteV1>,
+		message: Option<OfxMessageSuiteV1>,
 		message_v2: Option<OfxMessageSuiteV2>,
-		progress: OfxProgressSuiteV1,
-		progress_v2: Option<OfxProgressSuiteV2>,
-		time_line: OfxTimeLineSuiteV1,
-		parametric_parameter: Option<OfxParametricParameterSuiteV1>,
-		image_effect_opengl_render: Option<OfxImageEffectOpenGLRenderSuiteV1>,
+		progress: Option<ProgressSuite>,
+		time_line: Option<OfxTimeLineSuiteV1>,
+		image_effect_plane: Option<ImageEffectPlaneSuite>,
 	) -> Self {
 		Suites {
-			image_effect: Rc::new(image_effect),
-			property: Rc::new(property),
-			parameter: Rc::new(parameter),
-			memory: Rc::new(memory),
-			multi_thread: Rc::new(multi_thread),
-			message: Rc::new(message),
-			message_v2: message_v2.map(Rc::new),
-			progress: Rc::new(progress),
-			progress_v2: progress_v2.map(Rc::new),
-			time_line: Rc::new(time_line),
-			parametric_parameter: parametric_parameter.map(Rc::new),
-			image_effect_opengl_render: image_effect_opengl_render.map(Rc::new),
+			image_effect: Arc::new(image_effect),
+			property: Arc::new(property),
+			parameter: Arc::new(parameter),
+			memory: memory.map(Arc::new),
+			multi_thread: multi_thread.map(Arc::new),
+			message: message.map(Arc::new),
+			message_v2: message_v2.map(Arc::new),
+			progress,
+			time_line: time_line.map(Arc::new),
+			image_effect_plane,
+			#[cfg(feature = "parametric")]
+			parametric_parameter: None,
+			#[cfg(feature = "opengl")]
+			image_effect_opengl_render: None,
+			#[cfg(feature = "camera")]
+			camera: None,
 		}
 	}
 
-	pub fn image_effect(&self) -> Rc<OfxImageEffectSuiteV1> {
+	// extension suites are only fetched when their feature is enabled
+	#[cfg(feature = "parametric")]
+	pub fn with_parametric_parameter(
+		mut self,
+		parametric_parameter: Option<OfxParametricParameterSuiteV1>,
+	) -> Self {
+		self.parametric_parameter = parametric_parameter.map(Arc::new);
+		self
+	}
+
+	#[cfg(feature = "opengl")]
+	pub fn with_image_effect_opengl_render(
+		mut self,
+		image_effect_opengl_render: Option<OfxImageEffectOpenGLRenderSuiteV1>,
+	) -> Self {
+		self.image_effect_opengl_render = image_effect_opengl_render.map(Arc::new);
+		self
+	}
+
+	#[cfg(feature = "camera")]
+	pub fn with_camera(mut self, camera: Option<NukeOfxCameraSuiteV1>) -> Self {
+		self.camera = camera.map(Arc::new);
+		self
+	}
+
+	pub fn image_effect(&self) -> Arc<OfxImageEffectSuiteV1> {
 		self.image_effect.clone()
 	}
 
-	pub fn property(&self) -> Rc<OfxPropertySuiteV1> {
+	pub fn property(&self) -> Arc<OfxPropertySuiteV1> {
 		self.property.clone()
 	}
 
-	pub fn parameter(&self) -> Rc<OfxParameterSuiteV1> {
+	pub fn parameter(&self) -> Arc<OfxParameterSuiteV1> {
 		self.parameter.clone()
 	}
+
+	pub fn memory(&self) -> Option<Arc<OfxMemorySuiteV1>> {
+		self.memory.clone()
+	}
+
+	pub fn multi_thread(&self) -> Option<Arc<OfxMultiThreadSuiteV1>> {
+		self.multi_thread.clone()
+	}
+
+	pub fn message(&self) -> Option<Arc<OfxMessageSuiteV1>> {
+		self.message.clone()
+	}
+
+	pub fn message_v2(&self) -> Option<Arc<OfxMessageSuiteV2>> {
+		self.message_v2.clone()
+	}
+
+	pub fn progress(&self) -> Option<ProgressSuite> {
+		self.progress.clone()
+	}
+
+	pub fn time_line(&self) -> Option<Arc<OfxTimeLineSuiteV1>> {
+		self.time_line.clone()
+	}
+
+	pub fn image_effect_plane(&self) -> Option<ImageEffectPlaneSuite> {
+		self.image_effect_plane.clone()
+	}
+
+	#[cfg(feature = "parametric")]
+	pub fn parametric_parameter(&self) -> Option<Arc<OfxParametricParameterSuiteV1>> {
+		self.parametric_parameter.clone()
+	}
+
+	#[cfg(feature = "opengl")]
+	pub fn image_effect_opengl_render(&self) -> Option<Arc<OfxImageEffectOpenGLRenderSuiteV1>> {
+		self.image_effect_opengl_render.clone()
+	}
+
+	#[cfg(feature = "camera")]
+	pub fn camera(&self) -> Option<Arc<NukeOfxCameraSuiteV1>> {
+		self.camera.clone()
+	}
+
+	pub fn has(&self, kind: SuiteKind) -> bool {
+		match kind {
+			SuiteKind::ImageEffect | SuiteKind::Property | SuiteKind::Parameter => true,
+			SuiteKind::Memory => self.memory.is_some(),
+			SuiteKind::MultiThread => self.multi_thread.is_some(),
+			SuiteKind::Message => self.message.is_some(),
+			SuiteKind::Progress => self.progress.is_some(),
+			SuiteKind::TimeLine => self.time_line.is_some(),
+			SuiteKind::ImageEffectPlane => self.image_effect_plane.is_some(),
+			#[cfg(feature = "parametric")]
+			SuiteKind::ParametricParameter => self.parametric_parameter.is_some(),
+			#[cfg(feature = "opengl")]
+			SuiteKind::ImageEffectOpenGLRender => self.image_effect_opengl_render.is_some(),
+			#[cfg(feature = "camera")]
+			SuiteKind::Camera => self.camera.is_some(),
+		}
+	}
+
+	/// The version obtained from the host, None if the suite is missing
+	pub fn version(&self, kind: SuiteKind) -> Option<Int> {
+		match kind {
+			SuiteKind::Message if self.message_v2.is_some() => Some(2),
+			SuiteKind::Progress => self.progress.as_ref().map(ProgressSuite::version),
+			SuiteKind::ImageEffectPlane => self
+				.image_effect_plane
+				.as_ref()
+				.map(ImageEffectPlaneSuite::version),
+			other if self.has(other) => Some(1),
+			_ => None,
+		}
+	}
+
+	pub fn supports(&self, feature: SuiteFeature) -> bool {
+		let (kind, min_version) = feature.requires();
+		self.version(kind)
+			.map(|version| version >= min_version)
+			.unwrap_or(false)
+	}
+
+	/// The first of the required suites the host did not provide, if any
+	pub fn find_missing(&self, required: &[SuiteKind]) -> Option<SuiteKind> {
+		required.iter().cloned().find(|&kind| !self.has(kind))
+	}
+}
+
+mod tests {
+	use super::*;
+	use std::mem;
+
+	#[test]
+	fn optional_suites_can_be_missing() {
+		let suites = unsafe {
+			Suites::new(
+				mem::zeroed(),
+				mem::zeroed(),
+				mem::zeroed(),
+				None,
+				None,
+				Some(mem::zeroed()),
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+		assert!(suites.has(SuiteKind::Property));
+		assert!(suites.has(SuiteKind::Message));
+		assert!(suites.multi_thread().is_none());
+		assert!(
+			suites.find_missing(&[SuiteKind::Message, SuiteKind::TimeLine])
+				== Some(SuiteKind::TimeLine)
+		);
+		assert!(suites.find_missing(&[SuiteKind::Message]) == None);
+	}
+
+	#[test]
+	fn negotiated_versions() {
+		let suites = unsafe {
+			Suites::new(
+				mem::zeroed(),
+				mem::zeroed(),
+				mem::zeroed(),
+				None,
+				None,
+				Some(mem::zeroed()),
+				Some(mem::zeroed()),
+				Some(ProgressSuite::V1(Arc::new(mem::zeroed()))),
+				None,
+				Some(ImageEffectPlaneSuite::V2(Arc::new(mem::zeroed()))),
+			)
+		};
+		assert!(suites.version(SuiteKind::Message) == Some(2));
+		assert!(suites.version(SuiteKind::TimeLine) == None);
+		assert!(suites.supports(SuiteFeature::PersistentMessages));
+		assert!(!suites.supports(SuiteFeature::ProgressMessageId));
+		assert!(suites.supports(SuiteFeature::ImagePlaneViews));
+	}
 }
@@ -0,0 +1,325 @@
+use handle::*;
+use image::*;
+use ofx_sys::*;
+use result::*;
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::sync::Mutex;
+use types::*;
+
+/// A mutex created by the host multithread suite, protecting a value of type T.
+/// The host mutex counts locks from the same thread, locking it again from the
+/// thread holding it is an error rather than a second guard to the same value
+pub struct HostMutex<T> {
+	inner: OfxMutexHandle,
+	multi_thread: Arc<OfxMultiThreadSuiteV1>,
+	owner: AtomicUsize,
+	value: UnsafeCell<T>,
+}
+
+thread_local!(static THREAD_TOKEN: u8 = const { 0 });
+
+// the address of a thread local, unique among the live threads and never 0
+fn current_thread_token() -> usize {
+	THREAD_TOKEN.with(|token| token as *const u8 as usize)
+}
+
+pub struct HostMutexGuard<'a, T: 'a> {
+	mutex: &'a HostMutex<T>,
+}
+
+// the mutex handle can be used from any thread, access to the value is
+// serialised by the host mutex
+unsafe impl<T> Send for HostMutex<T> where T: Send {}
+unsafe impl<T> Sync for HostMutex<T> where T: Send {}
+
+impl<T> HostMutex<T> {
+	pub fn new(multi_thread: Arc<OfxMultiThreadSuiteV1>, value: T) -> Result<Self> {
+		let mut inner: OfxMutexHandle = ptr::null_mut();
+		suite_fn!(mutexCreate in multi_thread; &mut inner as *mut _, 0)?;
+		Ok(HostMutex {
+			inner,
+			multi_thread,
+			owner: AtomicUsize::new(0),
+			value: UnsafeCell::new(value),
+		})
+	}
+
+	// only the thread holding the lock can have stored its own token
+	fn check_not_owner(&self) -> Result<usize> {
+		let thread = current_thread_token();
+		if self.owner.load(Ordering::Acquire) == thread {
+			Err(Error::MutexReentered)
+		} else {
+			Ok(thread)
+		}
+	}
+
+	fn guard(&self, thread: usize) -> HostMutexGuard<T> {
+		self.owner.store(thread, Ordering::Release);
+		HostMutexGuard { mutex: self }
+	}
+
+	/// Fails with MutexReentered if this thread already holds the lock
+	pub fn lock(&self) -> Result<HostMutexGuard<T>> {
+		let thread = self.check_not_owner()?;
+		suite_fn!(mutexLock in self.multi_thread; self.inner)?;
+		Ok(self.guard(thread))
+	}
+
+	/// Returns None if the mutex is already locked by another thread, fails with
+	/// MutexReentered if this thread already holds the lock
+	pub fn try_lock(&self) -> Result<Option<HostMutexGuard<T>>> {
+		let thread = self.check_not_owner()?;
+		match suite_call!(mutexTryLock in self.multi_thread; self.inner) {
+			eOfxStatus_OK => Ok(Some(self.guard(thread))),
+			eOfxStatus_Failed => Ok(None),
+			other => Err(Error::from(other)),
+		}
+	}
+
+	pub fn get_mut(&mut self) -> &mut T {
+		unsafe { &mut *self.value.get() }
+	}
+
+	fn unlock(&self) -> Result<()> {
+		suite_fn!(mutexUnLock in self.multi_thread; self.inner)
+	}
+
+	fn destroy(&mut self) -> Result<()> {
+		suite_fn!(mutexDestroy in self.multi_thread; self.inner)
+	}
+}
+
+impl<T> Drop for HostMutex<T> {
+	fn drop(&mut self) {
+		if self.destroy().is_err() {
+			error!("Unable to destroy host mutex {:?}", self.inner);
+		}
+	}
+}
+
+impl<'a, T> Deref for HostMutexGuard<'a, T> {
+	type Target = T;
+	fn deref(&self) -> &T {
+		unsafe { &*self.mutex.value.get() }
+	}
+}
+
+impl<'a, T> DerefMut for HostMutexGuard<'a, T> {
+	fn deref_mut(&mut self) -> &mut T {
+		unsafe { &mut *self.mutex.value.get() }
+	}
+}
+
+impl<'a, T> Drop for HostMutexGuard<'a, T> {
+	fn drop(&mut self) {
+		self.mutex.owner.store(0, Ordering::Release);
+		if self.mutex.unlock().is_err() {
+			error!("Unable to unlock host mutex {:?}", self.mutex.inner);
+		}
+	}
+}
+
+/// Work shared by reference between all the threads spawned by the host
+pub(crate) trait SharedRunnable: Sync {
+	fn run(&self, thread_index: UnsignedInt, thread_max: UnsignedInt);
+}
+
+unsafe extern "C" fn run_shared<R>(
+	thread_index: UnsignedInt,
+	thread_max: UnsignedInt,
+	arg: VoidPtrMut,
+) where
+	R: SharedRunnable,
+{
+	(*(arg as *const R)).run(thread_index, thread_max)
+}
+
+fn spawn_shared<R>(
+	multi_thread: &OfxMultiThreadSuiteV1,
+	n_threads: UnsignedInt,
+	runnable: &R,
+) -> Result<()>
+where
+	R: SharedRunnable,
+{
+	let arg = (runnable as *const R) as VoidPtrMut;
+	suite_fn!(multiThread in *multi_thread; Some(run_shared::<R>), n_threads, arg)
+}
+
+/// Runs on n_threads host threads, or on the calling thread if the host can't spawn them.
+/// The suite call only returns once every thread is done, so the runnable can be borrowed.
+pub(crate) fn run_shared_in_threads<R>(
+	multi_thread: Option<&OfxMultiThreadSuiteV1>,
+	n_threads: UnsignedInt,
+	runnable: &R,
+) where
+	R: SharedRunnable,
+{
+	let multi_thread = match multi_thread {
+		Some(multi_thread) => multi_thread,
+		None => return runnable.run(0, 1),
+	};
+	if let Err(error) = spawn_shared(multi_thread, n_threads, runnable) {
+		warn!(
+			"Unable to spawn {} threads ({:?}), running on the calling thread",
+			n_threads, error
+		);
+		runnable.run(0, 1);
+	}
+}
+
+/// A queue of disjoint tiles, drained by all threads until empty, aborted or failed
+pub(crate) struct ParallelTiles<'a, 'f, T, F>
+where
+	T: PixelFormat + 'a,
+	F: 'f,
+{
+	tiles: Mutex<Vec<ImageTileMut<'a, T>>>,
+	function: &'f F,
+	effect: &'f ImageEffectHandle,
+	error: Mutex<Option<Error>>,
+}
+
+impl<'a, 'f, T, F> ParallelTiles<'a, 'f, T, F>
+where
+	T: PixelFormat + Send + 'a,
+	F: Fn(&mut ImageTileMut<'a, T>) -> Result<()> + Sync + 'f,
+{
+	pub fn new(
+		tiles: Vec<ImageTileMut<'a, T>>,
+		function: &'f F,
+		effect: &'f ImageEffectHandle,
+	) -> Self {
+		ParallelTiles {
+			tiles: Mutex::new(tiles),
+			function,
+			effect,
+			error: Mutex::new(None),
+		}
+	}
+
+	fn failed(&self) -> bool {
+		self.error
+			.lock()
+			.map(|error| error.is_some())
+			.unwrap_or(true)
+	}
+
+	fn fail(&self, error: Error) {
+		if let Ok(mut first_error) = self.error.lock() {
+			first_error.get_or_insert(error);
+		}
+	}
+
+	fn next_tile(&self) -> Option<ImageTileMut<'a, T>> {
+		if self.failed() || self.effect.abort().unwrap_or(false) {
+			None
+		} else {
+			self.tiles.lock().ok().and_then(|mut tiles| tiles.pop())
+		}
+	}
+
+	/// The first error or panic raised by any worker
+	pub fn into_result(self) -> Result<()> {
+		match self.error.into_inner() {
+			Ok(Some(error)) => Err(error),
+			Ok(None) => Ok(()),
+			Err(_) => Err(Error::WorkerPanicked),
+		}
+	}
+}
+
+impl<'a, 'f, T, F> SharedRunnable for ParallelTiles<'a, 'f, T, F>
+where
+	T: PixelFormat + Send + 'a,
+	F: Fn(&mut ImageTileMut<'a, T>) -> Result<()> + Sync + 'f,
+{
+	fn run(&self, _thread_index: UnsignedInt, _thread_max: UnsignedInt) {
+		// unwinding into the host is undefined behaviour, panics are caught
+		// and reported as errors instead
+		while let Some(mut tile) = self.next_tile() {
+			match panic::catch_unwind(AssertUnwindSafe(|| (self.function)(&mut tile))) {
+				Ok(Ok(())) => {}
+				Ok(Err(error)) => self.fail(error),
+				Err(_) => self.fail(Error::WorkerPanicked),
+			}
+		}
+	}
+}
+
+mod tests {
+	use super::*;
+
+	fn assert_send_sync<T: Send + Sync>() {}
+
+	#[test]
+	fn handles_are_send_sync() {
+		assert_send_sync::<ImageEffectHandle>();
+		assert_send_sync::<ImageEffectDescriptorHandle>();
+		assert_send_sync::<ClipInstance>();
+		assert_send_sync::<Image>();
+		assert_send_sync::<ParamHandle<Double>>();
+		assert_send_sync::<ParamSetHandle>();
+		assert_send_sync::<ParamSetDescriptorHandle>();
+		assert_send_sync::<HostMutex<Vec<Int>>>();
+	}
+
+	// a counting mutex like the one described by the multithread suite
+	unsafe extern "C" fn counting_mutex_create(
+		mutex: *mut OfxMutexHandle,
+		_count: Int,
+	) -> OfxStatus {
+		*mutex = Box::into_raw(Box::new(0 as Int)) as OfxMutexHandle;
+		eOfxStatus_OK
+	}
+
+	unsafe extern "C" fn counting_mutex_destroy(mutex: OfxMutexHandle) -> OfxStatus {
+		Box::from_raw(mutex as *mut Int);
+		eOfxStatus_OK
+	}
+
+	unsafe extern "C" fn counting_mutex_lock(mutex: OfxMutexHandle) -> OfxStatus {
+		*(mutex as *mut Int) += 1;
+		eOfxStatus_OK
+	}
+
+	unsafe extern "C" fn counting_mutex_unlock(mutex: OfxMutexHandle) -> OfxStatus {
+		*(mutex as *mut Int) -= 1;
+		eOfxStatus_OK
+	}
+
+	fn counting_multi_thread() -> Arc<OfxMultiThreadSuiteV1> {
+		let mut multi_thread: OfxMultiThreadSuiteV1 = unsafe { std::mem::zeroed() };
+		multi_thread.mutexCreate = Some(counting_mutex_create);
+		multi_thread.mutexDestroy = Some(counting_mutex_destroy);
+		multi_thread.mutexLock = Some(counting_mutex_lock);
+		multi_thread.mutexUnLock = Some(counting_mutex_unlock);
+		multi_thread.mutexTryLock = Some(counting_mutex_lock);
+		Arc::new(multi_thread)
+	}
+
+	#[test]
+	fn locking_twice_on_one_thread_fails() {
+		let mutex = HostMutex::new(counting_multi_thread(), 0).unwrap();
+		{
+			let mut guard = mutex.lock().unwrap();
+			*guard += 1;
+			assert!(match mutex.lock() {
+				Err(Error::MutexReentered) => true,
+				_ => false,
+			});
+			assert!(match mutex.try_lock() {
+				Err(Error::MutexReentered) => true,
+				_ => false,
+			});
+		}
+		assert!(*mutex.lock().unwrap() == 1);
+		assert!(mutex.try_lock().unwrap().is_some());
+	}
+}
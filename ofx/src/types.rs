@@ -25,6 +25,8 @@ pub const RANGE_ELEMENTS: Int = 2;
 pub type RectI = ofx_sys::OfxRectI;
 pub type RectD = ofx_sys::OfxRectD;
 pub const RECT_ELEMENTS: Int = 4;
+#[cfg(feature = "camera")]
+pub const CAMERA_MATRIX_ELEMENTS: Int = 16;
 pub type Time = ofx_sys::OfxTime;
 pub type ThreadFunction = ofx_sys::OfxThreadFunctionV1;
 pub type RGBAColourB = ofx_sys::OfxRGBAColourB;
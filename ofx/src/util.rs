@@ -53,6 +53,13 @@ macro_rules! image_clip_prop_depth {
 	};
 }
 
+#[macro_export]
+macro_rules! image_clip_prop_par {
+	($clip:expr) => {
+		concat!("OfxImageClipPropPAR_", $clip)
+	};
+}
+
 #[macro_export]
 macro_rules! static_str {
 	($name:expr) => { unsafe { CStr::from_bytes_with_nul_unchecked($name).as_ptr() } }